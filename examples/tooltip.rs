@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use iced::widget::{button, center, column, container};
+use iced::{Alignment::Center, Element};
+
+use sweeten::widget::overlay::Placement;
+use sweeten::widget::tooltip;
+
+fn main() -> iced::Result {
+    iced::run("sweetened iced - Tooltip example", App::update, App::view)
+}
+
+#[derive(Default)]
+struct App;
+
+#[derive(Clone, Debug)]
+enum Message {}
+
+impl App {
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        center(
+            column![
+                tooltip(
+                    button("Hover me for half a second"),
+                    "Thanks for waiting!",
+                )
+                .delay(Duration::from_millis(500))
+                .hide_delay(Duration::from_millis(250))
+                .style(container::rounded_box),
+                tooltip(button("I follow the cursor"), "Over here!")
+                    .follow_cursor(true)
+                    .placement(Placement::Bottom)
+                    .gap(10)
+                    .style(container::rounded_box),
+            ]
+            .align_x(Center)
+            .spacing(20),
+        )
+        .into()
+    }
+}
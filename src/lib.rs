@@ -1 +1,4 @@
 pub mod widget;
+
+#[cfg(feature = "test")]
+pub mod test;
@@ -0,0 +1,196 @@
+//! Simulate user interactions on widgets without opening a window.
+//!
+//! A [`Simulator`] lays out an [`Element`] in a headless user interface and
+//! lets you script the events a user would produce, collecting the messages
+//! published along the way. This module is only available with the `test`
+//! feature.
+//!
+//! # Example
+//! ```
+//! use iced::widget::Space;
+//! use iced::{Fill, Point};
+//! use sweeten::test::Simulator;
+//! use sweeten::widget::mouse_area;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Pressed(Point),
+//!     Released,
+//! }
+//!
+//! let mut simulator = Simulator::<_, iced::Theme>::new(
+//!     mouse_area(Space::new(Fill, Fill))
+//!         .on_press_with(Message::Pressed)
+//!         .on_release(Message::Released),
+//!     (200.0, 100.0),
+//! );
+//!
+//! assert_eq!(
+//!     simulator.click((5.0, 5.0)),
+//!     vec![Message::Pressed(Point::new(5.0, 5.0)), Message::Released],
+//! );
+//! ```
+use iced::advanced::clipboard;
+use iced::keyboard::{self, key};
+use iced::time::Instant;
+use iced::touch;
+use iced::window;
+use iced::{mouse, Element, Event, Point, Size};
+use iced_runtime::user_interface::{self, UserInterface};
+
+/// A headless user interface that feeds scripted events into an [`Element`].
+///
+/// The [`Simulator`] keeps the widget state between calls, so sequences of
+/// interactions (e.g. a double click) can be split across several calls.
+/// Widgets are laid out with the null renderer `()`, which measures every
+/// piece of text as empty, so content that should be hit by the cursor needs
+/// an explicit size.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = iced::Theme> {
+    interface: UserInterface<'a, Message, Theme, ()>,
+    cursor: mouse::Cursor,
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme> {
+    /// Creates a new [`Simulator`] laying out the given `element` within a
+    /// viewport of the given `size`.
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, ()>>,
+        size: impl Into<Size>,
+    ) -> Self {
+        Self {
+            interface: UserInterface::build(
+                element,
+                size.into(),
+                user_interface::Cache::default(),
+                &mut (),
+            ),
+            cursor: mouse::Cursor::Unavailable,
+        }
+    }
+
+    /// Returns the current [`mouse::Cursor`] of the [`Simulator`].
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor
+    }
+
+    /// Processes the given events in order and returns every message
+    /// published as a result.
+    ///
+    /// Cursor and touch events update the [`mouse::Cursor`] before being
+    /// delivered, just like the runtime does.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+
+        for event in events {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerPressed {
+                    position, ..
+                })
+                | Event::Touch(touch::Event::FingerMoved {
+                    position, ..
+                }) => {
+                    self.cursor = mouse::Cursor::Available(position);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    self.cursor = mouse::Cursor::Unavailable;
+                }
+                _ => {}
+            }
+
+            let _ = self.interface.update(
+                &[event],
+                self.cursor,
+                &mut (),
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    /// Moves the cursor to the given `position`.
+    pub fn move_to(&mut self, position: impl Into<Point>) -> Vec<Message> {
+        let position = position.into();
+
+        self.simulate([Event::Mouse(mouse::Event::CursorMoved { position })])
+    }
+
+    /// Presses the given [`mouse::Button`] at the current cursor position.
+    pub fn press(&mut self, button: mouse::Button) -> Vec<Message> {
+        self.simulate([Event::Mouse(mouse::Event::ButtonPressed(button))])
+    }
+
+    /// Releases the given [`mouse::Button`] at the current cursor position.
+    pub fn release(&mut self, button: mouse::Button) -> Vec<Message> {
+        self.simulate([Event::Mouse(mouse::Event::ButtonReleased(button))])
+    }
+
+    /// Moves the cursor to `position`, then presses and releases the left
+    /// mouse button.
+    pub fn click(&mut self, position: impl Into<Point>) -> Vec<Message> {
+        let position = position.into();
+
+        self.simulate([
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ])
+    }
+
+    /// Scrolls the mouse wheel by the given `delta`.
+    pub fn scroll(&mut self, delta: mouse::ScrollDelta) -> Vec<Message> {
+        self.simulate([Event::Mouse(mouse::Event::WheelScrolled { delta })])
+    }
+
+    /// Presses and lifts a finger at the given `position`.
+    pub fn tap(&mut self, position: impl Into<Point>) -> Vec<Message> {
+        let position = position.into();
+        let id = touch::Finger(0);
+
+        self.simulate([
+            Event::Touch(touch::Event::FingerPressed { id, position }),
+            Event::Touch(touch::Event::FingerLifted { id, position }),
+        ])
+    }
+
+    /// Presses and releases the given [`keyboard::Key`] with the given
+    /// [`keyboard::Modifiers`].
+    pub fn press_key(
+        &mut self,
+        key: impl Into<keyboard::Key>,
+        modifiers: keyboard::Modifiers,
+    ) -> Vec<Message> {
+        let key = key.into();
+        let location = keyboard::Location::Standard;
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                modified_key: key.clone(),
+                physical_key: key::Physical::Unidentified(
+                    key::NativeCode::Unidentified,
+                ),
+                location,
+                modifiers,
+                text: None,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location,
+                modifiers,
+            }),
+        ])
+    }
+
+    /// Produces a redraw at the given instant, letting time-based widgets
+    /// (e.g. a [`Tooltip`](crate::widget::tooltip::Tooltip) delay) advance.
+    pub fn redraw(&mut self, now: Instant) -> Vec<Message> {
+        self.simulate([Event::Window(window::Event::RedrawRequested(now))])
+    }
+}
@@ -1,10 +1,12 @@
 use iced::advanced::text;
 use iced::Element;
 use std::borrow::Borrow;
+use std::fmt;
 
 pub mod mouse_area;
 pub mod overlay;
 pub mod pick_list;
+pub mod tooltip;
 
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Theme, Renderer>(
@@ -34,3 +36,58 @@ where
 {
     pick_list::PickList::new(options, disabled, selected, on_selected)
 }
+
+/// Pick lists whose options are identified by a key instead of their
+/// [`PartialEq`] implementation, which they do not need to have.
+pub fn pick_list_with_key<'a, T, K, L, V, Message, Theme, Renderer>(
+    options: L,
+    disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
+    selected: Option<V>,
+    on_selected: impl Fn(T) -> Message + 'a,
+    key: impl Fn(&T) -> K + 'a,
+) -> pick_list::PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + Clone + 'a,
+    K: PartialEq,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Message: Clone,
+    Theme: pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: text::Renderer,
+{
+    pick_list::PickList::with_key(options, disabled, selected, on_selected, key)
+}
+
+/// Pick lists whose options may be of different types, identified by their
+/// index.
+pub fn pick_list_from_display<'a, Message, Theme, Renderer>(
+    options: Vec<Box<dyn fmt::Display + 'a>>,
+    disabled: Option<impl Fn(&[usize]) -> Vec<bool> + 'a>,
+    selected: Option<usize>,
+    on_select_with_index: impl Fn(usize) -> Message + 'a,
+) -> pick_list::PickList<'a, usize, Vec<usize>, usize, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: pick_list::Catalog + overlay::menu::Catalog,
+    Renderer: text::Renderer,
+{
+    pick_list::PickList::from_display(
+        options,
+        disabled,
+        selected,
+        on_select_with_index,
+    )
+}
+
+/// Tooltips display a hint of information over some element when hovered,
+/// optionally after a delay.
+pub fn tooltip<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> tooltip::Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: iced::widget::container::Catalog + 'a,
+    Renderer: iced::advanced::renderer::Renderer,
+{
+    tooltip::Tooltip::new(content, tooltip)
+}
@@ -22,13 +22,18 @@
 // COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::widget::{operation, tree, Id, Operation, Tree, Widget};
 use iced::advanced::{
     layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
 };
 use iced::event::{self, Event};
+use iced::time::{Duration, Instant};
 use iced::touch;
-use iced::{Element, Length, Point, Rectangle, Size, Vector};
+use iced::window;
+use iced::{
+    Background, Border, Color, Element, Length, Point, Rectangle, Size, Theme,
+    Vector,
+};
 
 /// Emit messages on mouse events.
 #[allow(missing_debug_implementations)]
@@ -40,29 +45,66 @@ pub struct MouseArea<
 > {
     content: Element<'a, Message, Theme, Renderer>,
     on_press: Option<OnPress<'a, Message>>,
+    on_press_outside: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_release: Option<Message>,
+    on_any_release: Option<Box<dyn Fn(mouse::Button, Point) -> Message + 'a>>,
     on_double_click: Option<Message>,
+    double_click_interval: Option<Duration>,
     on_right_press: Option<Message>,
+    on_right_double_click: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
     on_middle_release: Option<Message>,
-    on_scroll: Option<Box<dyn Fn(mouse::ScrollDelta) -> Message + 'a>>,
+    on_middle_drag: Option<Box<dyn Fn(Vector) -> Message + 'a>>,
+    on_scroll: Option<OnScroll<'a, Message>>,
+    scroll_line_height: f32,
+    on_gesture: Option<Box<dyn Fn(Gesture) -> Message + 'a>>,
     on_enter: Option<Message>,
     on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    move_throttle: Option<Duration>,
+    move_min_distance: f32,
     on_exit: Option<Message>,
+    segments: Option<(usize, Axis)>,
+    on_segment_enter: Option<Box<dyn Fn(usize, Point) -> Message + 'a>>,
     interaction: Option<mouse::Interaction>,
+    peek: bool,
+    snap: Option<Size>,
+    min_size: Size,
+    id: Option<Id>,
+    style: Option<StyleFn<'a, Theme>>,
 }
 
 enum OnPress<'a, Message> {
     Direct(Message),
     Closure(Box<dyn Fn(Point) -> Message + 'a>),
+    WithSource(Box<dyn Fn(Point, InputSource) -> Message + 'a>),
 }
 
 impl<'a, Message: Clone> OnPress<'a, Message> {
-    fn get(&self, point: Point) -> Message {
+    fn get(&self, point: Point, source: InputSource) -> Message {
         match self {
             OnPress::Direct(message) => message.clone(),
             OnPress::Closure(f) => f(point),
+            OnPress::WithSource(f) => f(point, source),
+        }
+    }
+}
+
+enum OnScroll<'a, Message> {
+    Delta(Box<dyn Fn(mouse::ScrollDelta) -> Message + 'a>),
+    Pixels(Box<dyn Fn(Vector) -> Message + 'a>),
+}
+
+impl<'a, Message> OnScroll<'a, Message> {
+    fn get(&self, delta: mouse::ScrollDelta, line_height: f32) -> Message {
+        match self {
+            OnScroll::Delta(f) => f(delta),
+            OnScroll::Pixels(f) => f(match delta {
+                mouse::ScrollDelta::Lines { x, y } => {
+                    Vector::new(x, y) * line_height
+                }
+                mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+            }),
         }
     }
 }
@@ -92,6 +134,20 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets the message to emit on a left button press or a tap, along with
+    /// the [`InputSource`] that produced it.
+    ///
+    /// This is analogous to [`MouseArea::on_press_with`], but allows telling
+    /// mouse and touch presses apart on hybrid devices.
+    #[must_use]
+    pub fn on_press_with_source(
+        mut self,
+        on_press: impl Fn(Point, InputSource) -> Message + 'a,
+    ) -> Self {
+        self.on_press = Some(OnPress::WithSource(Box::new(on_press)));
+        self
+    }
+
     /// Sets the message to emit on a left button press, if `Some`.
     ///
     /// If `None`, the press event will be ignored.
@@ -101,6 +157,21 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when the left button is pressed outside of the
+    /// area.
+    ///
+    /// The closure receives the absolute cursor position. The event is not
+    /// captured, so it can be used to dismiss popups while still reaching the
+    /// widget under the cursor.
+    #[must_use]
+    pub fn on_press_outside(
+        mut self,
+        on_press_outside: impl Fn(Point) -> Message + 'a,
+    ) -> Self {
+        self.on_press_outside = Some(Box::new(on_press_outside));
+        self
+    }
+
     /// The message to emit on a left button release.
     #[must_use]
     pub fn on_release(mut self, message: Message) -> Self {
@@ -108,6 +179,23 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit when any mouse button is released, given the
+    /// released [`mouse::Button`] and the position of the release.
+    ///
+    /// This fires for every button, including [`mouse::Button::Other`], and
+    /// for lifted fingers as [`mouse::Button::Left`]. Handlers for specific
+    /// buttons, like [`on_release`], still fire alongside it.
+    ///
+    /// [`on_release`]: Self::on_release
+    #[must_use]
+    pub fn on_any_release(
+        mut self,
+        on_any_release: impl Fn(mouse::Button, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_any_release = Some(Box::new(on_any_release));
+        self
+    }
+
     /// The message to emit on a double click.
     ///
     /// If you use this with [`on_press`]/[`on_release`], those
@@ -116,6 +204,9 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
     /// The events stream will be: on_press -> on_release -> on_press
     /// -> on_double_click -> on_release -> on_press ...
     ///
+    /// Double taps of a single finger on a touchscreen are reported too. Taps
+    /// only need to land close to each other, not on the exact same position.
+    ///
     /// [`on_press`]: Self::on_press
     /// [`on_release`]: Self::on_release
     #[must_use]
@@ -124,6 +215,17 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets the maximum time between two presses for them to count as a
+    /// double click.
+    ///
+    /// By default, the double click timing of [`mouse::Click`] is used. The
+    /// interval applies to double taps as well.
+    #[must_use]
+    pub fn double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = Some(interval);
+        self
+    }
+
     /// The message to emit on a right button press.
     #[must_use]
     pub fn on_right_press(mut self, message: Message) -> Self {
@@ -131,6 +233,21 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// The message to emit on a right button double click, given the
+    /// position of the second press.
+    ///
+    /// Right clicks are chained independently of left clicks, so a left click
+    /// never completes or breaks a right double click. The
+    /// [`double_click_interval`](Self::double_click_interval) applies as well.
+    #[must_use]
+    pub fn on_right_double_click(
+        mut self,
+        on_right_double_click: impl Fn(Point) -> Message + 'a,
+    ) -> Self {
+        self.on_right_double_click = Some(Box::new(on_right_double_click));
+        self
+    }
+
     /// The message to emit on a right button release.
     #[must_use]
     pub fn on_right_release(mut self, message: Message) -> Self {
@@ -152,13 +269,72 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
-    /// The message to emit when scroll wheel is used
+    /// The message to emit when the cursor moves while the middle button,
+    /// pressed over the area, is held down, e.g. to pan a view.
+    ///
+    /// The closure receives the movement since the previous message. Movements
+    /// are reported even if the cursor leaves the area, until the middle
+    /// button is released. Left button interactions are not affected.
+    #[must_use]
+    pub fn on_middle_drag(
+        mut self,
+        on_middle_drag: impl Fn(Vector) -> Message + 'a,
+    ) -> Self {
+        self.on_middle_drag = Some(Box::new(on_middle_drag));
+        self
+    }
+
+    /// The message to emit when scroll wheel is used.
+    ///
+    /// Dragging a finger that was pressed over the area is reported as
+    /// [`mouse::ScrollDelta::Pixels`] too, following the finger like content
+    /// in a scrollable would. Only the first finger is tracked, so additional
+    /// fingers do not produce jumpy deltas.
     #[must_use]
     pub fn on_scroll(
         mut self,
         on_scroll: impl Fn(mouse::ScrollDelta) -> Message + 'a,
     ) -> Self {
-        self.on_scroll = Some(Box::new(on_scroll));
+        self.on_scroll = Some(OnScroll::Delta(Box::new(on_scroll)));
+        self
+    }
+
+    /// The message to emit when scroll wheel is used, with the delta in
+    /// pixels.
+    ///
+    /// This is analogous to [`MouseArea::on_scroll`], but
+    /// [`mouse::ScrollDelta::Lines`] are converted to pixels using the
+    /// [`scroll_line_height`](Self::scroll_line_height).
+    #[must_use]
+    pub fn on_scroll_pixels(
+        mut self,
+        on_scroll: impl Fn(Vector) -> Message + 'a,
+    ) -> Self {
+        self.on_scroll = Some(OnScroll::Pixels(Box::new(on_scroll)));
+        self
+    }
+
+    /// Sets the height of a line, in pixels, used by
+    /// [`MouseArea::on_scroll_pixels`]. Defaults to `16.0`.
+    #[must_use]
+    pub fn scroll_line_height(mut self, line_height: f32) -> Self {
+        self.scroll_line_height = line_height;
+        self
+    }
+
+    /// The message to emit for each step of a left button or touch
+    /// interaction that starts over the area.
+    ///
+    /// A [`Gesture::Start`] is followed by a [`Gesture::Update`] for every
+    /// movement, even outside of the area, and finishes with either a
+    /// [`Gesture::End`] when released over the area or a [`Gesture::Cancel`]
+    /// otherwise. Positions are relative to the top-left corner of the area.
+    #[must_use]
+    pub fn on_gesture(
+        mut self,
+        on_gesture: impl Fn(Gesture) -> Message + 'a,
+    ) -> Self {
+        self.on_gesture = Some(Box::new(on_gesture));
         self
     }
 
@@ -176,6 +352,26 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Sets the minimum time between two [`on_move`](Self::on_move) messages.
+    ///
+    /// Movements within the interval are coalesced and the latest cursor
+    /// position is emitted once the interval has elapsed. By default, every
+    /// movement is emitted.
+    #[must_use]
+    pub fn move_throttle(mut self, interval: Duration) -> Self {
+        self.move_throttle = Some(interval);
+        self
+    }
+
+    /// Sets the minimum distance, in logical pixels, the cursor must travel
+    /// from the last emitted position before [`on_move`](Self::on_move) is
+    /// emitted again. By default, every movement is emitted.
+    #[must_use]
+    pub fn move_min_distance(mut self, distance: f32) -> Self {
+        self.move_min_distance = distance;
+        self
+    }
+
     /// The message to emit when the mouse exits the area.
     #[must_use]
     pub fn on_exit(mut self, message: Message) -> Self {
@@ -183,21 +379,151 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// Divides the area into `count` segments of equal size along the given
+    /// [`Axis`], so [`on_segment_enter`](Self::on_segment_enter) can report
+    /// which one is hovered.
+    ///
+    /// This is a lightweight alternative to wrapping each item of uniform
+    /// content in its own [`MouseArea`].
+    #[must_use]
+    pub fn segments(mut self, count: usize, axis: Axis) -> Self {
+        self.segments = Some((count, axis));
+        self
+    }
+
+    /// The message to emit when the cursor enters one of the
+    /// [`segments`](Self::segments) of the area.
+    ///
+    /// The closure receives the index of the segment and the cursor position
+    /// relative to the area.
+    #[must_use]
+    pub fn on_segment_enter(
+        mut self,
+        on_segment_enter: impl Fn(usize, Point) -> Message + 'a,
+    ) -> Self {
+        self.on_segment_enter = Some(Box::new(on_segment_enter));
+        self
+    }
+
     /// The [`mouse::Interaction`] to use when hovering the area.
     #[must_use]
     pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
         self.interaction = Some(interaction);
         self
     }
+
+    /// Sets the [`Id`] of the [`MouseArea`], so its [`State`] can be queried
+    /// with the [`state`] operation.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets whether the [`MouseArea`] only peeks at events.
+    ///
+    /// When peeking, the [`MouseArea`] handles every event before its content
+    /// and never captures it, so its messages are published first and the
+    /// content always processes the same event afterwards. The resulting
+    /// [`event::Status`] is the one of the content. This is useful to observe
+    /// interactions with an interactive content, e.g. a button, without
+    /// stealing them.
+    #[must_use]
+    pub fn peek(mut self, peek: bool) -> Self {
+        self.peek = peek;
+        self
+    }
+
+    /// Sets the minimum [`Size`] of the [`MouseArea`].
+    ///
+    /// The area reserves at least this size, even if its content is smaller
+    /// or empty, keeping a stable hit region. The content is laid out at the
+    /// top-left corner of the area.
+    #[must_use]
+    pub fn min_size(mut self, min_size: impl Into<Size>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    /// Snaps the positions passed to the press, move and release handlers to
+    /// a grid with cells of the given [`Size`].
+    ///
+    /// Positions are rounded to the nearest multiple of the cell size, with
+    /// the grid originating at the top-left corner of the [`MouseArea`].
+    #[must_use]
+    pub fn snap(mut self, cell: impl Into<Size>) -> Self {
+        self.snap = Some(cell.into());
+        self
+    }
+
+    /// Sets the style of the [`MouseArea`].
+    ///
+    /// The resulting [`Style`] is drawn behind the content. Without a style,
+    /// nothing is drawn, so the [`MouseArea`] works with any theme.
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme, Status) -> Style + 'a,
+    ) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the [`MouseArea`], for themes implementing
+    /// its [`Catalog`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self
+    where
+        Theme: Catalog,
+        Theme::Class<'a>: 'a,
+    {
+        let class = class.into();
+
+        self.style = Some(Box::new(move |theme: &Theme, status| {
+            theme.style(&class, status)
+        }));
+        self
+    }
 }
 
 /// Local state of the [`MouseArea`].
-#[derive(Default)]
-struct State {
+///
+/// It can be queried with the [`state`] operation.
+#[derive(Debug, Clone, Default)]
+pub struct State {
     is_hovered: bool,
+    is_pressed: bool,
     bounds: Rectangle,
     cursor_position: Option<Point>,
     previous_click: Option<mouse::Click>,
+    previous_press: Option<(Point, Instant, bool)>,
+    previous_right_click: Option<mouse::Click>,
+    previous_right_press: Option<(Point, Instant, bool)>,
+    tap_finger: Option<touch::Finger>,
+    middle_drag: Option<Point>,
+    touch: Option<(touch::Finger, Point)>,
+    is_gesturing: bool,
+    segment: Option<usize>,
+    last_move: Option<(Point, Instant)>,
+    is_move_pending: bool,
+}
+
+impl State {
+    /// Returns whether the cursor is over the [`MouseArea`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Returns whether the [`MouseArea`] is being pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.is_pressed
+    }
+
+    /// Returns the last known position of the cursor, in absolute
+    /// coordinates.
+    pub fn cursor_position(&self) -> Option<Point> {
+        self.cursor_position
+    }
 }
 
 impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
@@ -208,19 +534,52 @@ impl<'a, Message, Theme, Renderer> MouseArea<'a, Message, Theme, Renderer> {
         MouseArea {
             content: content.into(),
             on_press: None,
+            on_press_outside: None,
             on_release: None,
+            on_any_release: None,
             on_double_click: None,
+            double_click_interval: None,
             on_right_press: None,
+            on_right_double_click: None,
             on_right_release: None,
             on_middle_press: None,
             on_middle_release: None,
+            on_middle_drag: None,
             on_scroll: None,
+            scroll_line_height: 16.0,
+            on_gesture: None,
             on_enter: None,
             on_move: None,
+            move_throttle: None,
+            move_min_distance: 0.0,
             on_exit: None,
+            segments: None,
+            on_segment_enter: None,
             interaction: None,
+            peek: false,
+            snap: None,
+            min_size: Size::ZERO,
+            id: None,
+            style: None,
         }
     }
+
+    /// Applies the [`snap`](Self::snap) grid, if any, to a relative position.
+    fn snapped(&self, position: Point) -> Point {
+        let Some(cell) = self.snap else {
+            return position;
+        };
+
+        let snap = |value: f32, step: f32| {
+            if step > 0.0 {
+                (value / step).round() * step
+            } else {
+                value
+            }
+        };
+
+        Point::new(snap(position.x, cell.width), snap(position.y, cell.height))
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -255,9 +614,19 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.content
-            .as_widget()
-            .layout(&mut tree.children[0], renderer, limits)
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            content.size().max(self.min_size),
+        );
+
+        layout::Node::with_children(size, vec![content])
     }
 
     fn operate(
@@ -267,9 +636,11 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        operation.custom(tree.state.downcast_mut::<State>(), self.id.as_ref());
+
         self.content.as_widget().operate(
             &mut tree.children[0],
-            layout,
+            layout.children().next().unwrap(),
             renderer,
             operation,
         );
@@ -286,16 +657,54 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.peek {
+            let _ = update(self, tree, event.clone(), layout, cursor, shell);
+
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
         if let event::Status::Captured = self.content.as_widget_mut().on_event(
             &mut tree.children[0],
             event.clone(),
-            layout,
+            layout.children().next().unwrap(),
             cursor,
             renderer,
             clipboard,
             shell,
             viewport,
         ) {
+            // Hover transitions, gestures and releases are tracked even if
+            // the content captured the event, so enter/exit, gestures, taps
+            // and middle button drags can never get stuck.
+            update_hover(self, tree, layout, cursor, shell);
+            update_gesture(self, tree, &event, layout, cursor, shell, false);
+
+            let state: &mut State = tree.state.downcast_mut();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Middle,
+                )) => {
+                    state.middle_drag = None;
+                }
+                Event::Touch(
+                    touch::Event::FingerLifted { id, .. }
+                    | touch::Event::FingerLost { id, .. },
+                ) if state.tap_finger == Some(id) => {
+                    state.tap_finger = None;
+                }
+                _ => {}
+            }
+
             return event::Status::Captured;
         }
 
@@ -312,7 +721,7 @@ where
     ) -> mouse::Interaction {
         let content_interaction = self.content.as_widget().mouse_interaction(
             &tree.children[0],
-            layout,
+            layout.children().next().unwrap(),
             cursor,
             viewport,
             renderer,
@@ -338,12 +747,40 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        if let Some(style) = &self.style {
+            let state: &State = tree.state.downcast_ref();
+            let bounds = layout.bounds();
+
+            let status = if state.is_pressed {
+                Status::Pressed
+            } else if cursor.is_over(bounds) {
+                Status::Hovered
+            } else {
+                Status::Active
+            };
+
+            let style = style(theme, status);
+
+            if style.background.is_some() || style.border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+        }
+
         self.content.as_widget().draw(
             &tree.children[0],
             renderer,
             theme,
             renderer_style,
-            layout,
+            layout.children().next().unwrap(),
             cursor,
             viewport,
         );
@@ -358,7 +795,7 @@ where
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         self.content.as_widget_mut().overlay(
             &mut tree.children[0],
-            layout,
+            layout.children().next().unwrap(),
             renderer,
             translation,
         )
@@ -379,16 +816,15 @@ where
     }
 }
 
-/// Processes the given [`Event`] and updates the [`State`] of an [`MouseArea`]
-/// accordingly.
-fn update<Message: Clone, Theme, Renderer>(
-    widget: &mut MouseArea<'_, Message, Theme, Renderer>,
+/// Tracks the cursor position and publishes the enter, move and exit
+/// messages of a [`MouseArea`] on hover transitions.
+fn update_hover<Message: Clone, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
     tree: &mut Tree,
-    event: Event,
     layout: Layout<'_>,
     cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
-) -> event::Status {
+) {
     let state: &mut State = tree.state.downcast_mut();
 
     let cursor_position = cursor.position();
@@ -410,8 +846,40 @@ fn update<Message: Clone, Theme, Renderer>(
                 shell.publish(on_enter.clone());
             }
             (_, Some(on_move), _) if state.is_hovered => {
-                if let Some(position) = cursor.position_in(layout.bounds()) {
-                    shell.publish(on_move(position));
+                if let Some(position) = cursor
+                    .position_in(layout.bounds())
+                    .map(|position| widget.snapped(position))
+                {
+                    let now = Instant::now();
+
+                    let has_moved_enough = match state.last_move {
+                        Some((last_position, _)) => {
+                            last_position.distance(position)
+                                >= widget.move_min_distance
+                        }
+                        None => true,
+                    };
+
+                    let throttled_until = widget
+                        .move_throttle
+                        .zip(state.last_move)
+                        .map(|(interval, (_, emitted_at))| {
+                            emitted_at + interval
+                        })
+                        .filter(|until| now < *until);
+
+                    if has_moved_enough {
+                        if let Some(until) = throttled_until {
+                            state.is_move_pending = true;
+                            shell.request_redraw(window::RedrawRequest::At(
+                                until,
+                            ));
+                        } else {
+                            state.last_move = Some((position, now));
+                            state.is_move_pending = false;
+                            shell.publish(on_move(position));
+                        }
+                    }
                 }
             }
             (_, _, Some(on_exit)) if !state.is_hovered && was_hovered => {
@@ -419,6 +887,297 @@ fn update<Message: Clone, Theme, Renderer>(
             }
             _ => {}
         }
+
+        if !state.is_hovered {
+            state.last_move = None;
+            state.is_move_pending = false;
+        }
+
+        let segment = widget.segments.and_then(|(count, axis)| {
+            let position = cursor.position_in(bounds)?;
+
+            let (offset, length) = match axis {
+                Axis::Horizontal => (position.x, bounds.width),
+                Axis::Vertical => (position.y, bounds.height),
+            };
+
+            (count > 0 && length > 0.0).then(|| {
+                ((offset / length * count as f32) as usize).min(count - 1)
+            })
+        });
+
+        if segment != state.segment {
+            state.segment = segment;
+
+            if let (Some(segment), Some(on_segment_enter)) =
+                (segment, widget.on_segment_enter.as_ref())
+            {
+                if let Some(position) = cursor.position_in(bounds) {
+                    shell.publish(on_segment_enter(segment, position));
+                }
+            }
+        }
+    }
+}
+
+/// Emits the latest cursor position of a [`MouseArea`] once the throttle
+/// interval of a coalesced movement has elapsed.
+fn flush_move<Message, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
+    state: &mut State,
+    now: Instant,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+) {
+    let (Some(on_move), Some(interval), Some((_, emitted_at))) = (
+        widget.on_move.as_ref(),
+        widget.move_throttle,
+        state.last_move,
+    ) else {
+        return;
+    };
+
+    if now < emitted_at + interval {
+        shell.request_redraw(window::RedrawRequest::At(emitted_at + interval));
+        return;
+    }
+
+    state.is_move_pending = false;
+
+    if let Some(position) = cursor
+        .position_in(layout.bounds())
+        .map(|position| widget.snapped(position))
+    {
+        state.last_move = Some((position, now));
+        shell.publish(on_move(position));
+    }
+}
+
+/// Tracks a left button or touch interaction and publishes the [`Gesture`]
+/// messages of a [`MouseArea`].
+///
+/// A new gesture is only started if `can_start` is set, i.e. if the content
+/// did not capture the press.
+fn update_gesture<Message, Theme, Renderer>(
+    widget: &MouseArea<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    event: &Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    can_start: bool,
+) {
+    let Some(on_gesture) = widget.on_gesture.as_ref() else {
+        return;
+    };
+
+    let state: &mut State = tree.state.downcast_mut();
+    let bounds = layout.bounds();
+
+    let relative = |position: Point| {
+        widget.snapped(position - Vector::new(bounds.x, bounds.y))
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if can_start && !state.is_gesturing =>
+        {
+            if let Some(position) = cursor.position_over(bounds) {
+                state.is_gesturing = true;
+                shell.publish(on_gesture(Gesture::Start(relative(position))));
+            }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. })
+            if state.is_gesturing =>
+        {
+            if let Some(position) = cursor.position() {
+                shell.publish(on_gesture(Gesture::Update(relative(position))));
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+            if state.is_gesturing =>
+        {
+            state.is_gesturing = false;
+
+            shell.publish(on_gesture(
+                cursor
+                    .position_over(bounds)
+                    .map_or(Gesture::Cancel, |position| {
+                        Gesture::End(relative(position))
+                    }),
+            ));
+        }
+        Event::Touch(touch::Event::FingerLost { .. }) if state.is_gesturing => {
+            state.is_gesturing = false;
+
+            shell.publish(on_gesture(Gesture::Cancel));
+        }
+        _ => {}
+    }
+}
+
+/// The maximum time between two taps for them to count as a double click,
+/// matching the timing of [`mouse::Click`].
+const DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The maximum distance between two taps for them to count as a double click.
+const DOUBLE_TAP_DISTANCE: f32 = 10.0;
+
+/// Returns whether a press at `position` completes a double click, given the
+/// previous press.
+///
+/// Like [`mouse::Click`], consecutive presses alternate between double and
+/// triple clicks.
+fn is_double_press(
+    previous_press: Option<(Point, Instant, bool)>,
+    position: Point,
+    now: Instant,
+    interval: Duration,
+    max_distance: f32,
+) -> bool {
+    previous_press.is_some_and(
+        |(previous_position, pressed_at, was_double_press)| {
+            !was_double_press
+                && previous_position.distance(position) <= max_distance
+                && now.saturating_duration_since(pressed_at) <= interval
+        },
+    )
+}
+
+/// Processes the given [`Event`] and updates the [`State`] of an [`MouseArea`]
+/// accordingly.
+fn update<Message: Clone, Theme, Renderer>(
+    widget: &mut MouseArea<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status {
+    update_hover(widget, tree, layout, cursor, shell);
+    update_gesture(widget, tree, &event, layout, cursor, shell, true);
+
+    let state: &mut State = tree.state.downcast_mut();
+
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if state.is_move_pending {
+            flush_move(widget, state, now, layout, cursor, shell);
+        }
+    }
+
+    let cursor_position = cursor.position();
+    let bounds = layout.bounds();
+
+    if let Some(on_scroll) = widget.on_scroll.as_ref() {
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if state.touch.is_none() && bounds.contains(position) =>
+            {
+                state.touch = Some((id, position));
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some((finger, last_position)) = state.touch {
+                    if finger == id {
+                        let delta = position - last_position;
+
+                        state.touch = Some((finger, position));
+
+                        shell.publish(on_scroll.get(
+                            mouse::ScrollDelta::Pixels {
+                                x: delta.x,
+                                y: delta.y,
+                            },
+                            widget.scroll_line_height,
+                        ));
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. }
+                | touch::Event::FingerLost { id, .. },
+            ) if state.touch.is_some_and(|(finger, _)| finger == id) => {
+                state.touch = None;
+            }
+            _ => {}
+        }
+    }
+
+    // Only the first finger down can tap, so multi-touch does not produce
+    // double taps
+    let is_multi_touch = match event {
+        Event::Touch(touch::Event::FingerPressed { id, .. }) => {
+            let tap_finger = *state.tap_finger.get_or_insert(id);
+
+            tap_finger != id
+        }
+        Event::Touch(
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. },
+        ) => {
+            if state.tap_finger == Some(id) {
+                state.tap_finger = None;
+            }
+
+            false
+        }
+        _ => false,
+    };
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })
+            if cursor.is_over(bounds) =>
+        {
+            state.is_pressed = true;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(
+            touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+        ) => {
+            state.is_pressed = false;
+        }
+        _ => {}
+    }
+
+    if let Some(on_middle_drag) = widget.on_middle_drag.as_ref() {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                state.middle_drag = cursor.position_over(bounds);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(last_position) = state.middle_drag {
+                    state.middle_drag = Some(position);
+
+                    shell.publish(on_middle_drag(position - last_position));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Middle,
+            )) => {
+                state.middle_drag = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(on_press_outside) = widget.on_press_outside.as_ref() {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some(position) =
+                cursor_position.filter(|position| !bounds.contains(*position))
+            {
+                shell.publish(on_press_outside(position));
+            }
+        }
     }
 
     if !cursor.is_over(layout.bounds()) {
@@ -428,35 +1187,90 @@ fn update<Message: Clone, Theme, Renderer>(
     if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
     | Event::Touch(touch::Event::FingerPressed { .. }) = event
     {
-        let mut captured = false;
+        let mut captured = widget.on_gesture.is_some();
 
         if let Some(on_press) = widget.on_press.as_ref() {
             captured = true;
 
-            if let Some(position) = cursor.position_in(layout.bounds()) {
-                let message = on_press.get(position);
+            if let Some(position) = cursor
+                .position_in(layout.bounds())
+                .map(|position| widget.snapped(position))
+            {
+                let source = match event {
+                    Event::Touch(_) => InputSource::Touch,
+                    _ => InputSource::Mouse,
+                };
+
+                let message = on_press.get(position, source);
                 shell.publish(message);
             }
         }
 
-        if let Some(position) = cursor_position {
-            if let Some(message) = widget.on_double_click.as_ref() {
-                let new_click = mouse::Click::new(
-                    position,
-                    mouse::Button::Left,
-                    state.previous_click,
-                );
+        if let Some(message) = widget.on_double_click.as_ref() {
+            let now = Instant::now();
+
+            let is_double_click = match event {
+                Event::Touch(touch::Event::FingerPressed {
+                    position, ..
+                }) => {
+                    // Taps never land on the exact same spot, so they are
+                    // classified with some tolerance instead of `mouse::Click`
+                    let is_double_tap = !is_multi_touch
+                        && is_double_press(
+                            state.previous_press,
+                            position,
+                            now,
+                            widget
+                                .double_click_interval
+                                .unwrap_or(DOUBLE_TAP_INTERVAL),
+                            DOUBLE_TAP_DISTANCE,
+                        );
+
+                    state.previous_press = (!is_multi_touch).then_some((
+                        position,
+                        now,
+                        is_double_tap,
+                    ));
 
-                if matches!(new_click.kind(), mouse::click::Kind::Double) {
-                    shell.publish(message.clone());
+                    is_double_tap
                 }
+                _ => match (cursor_position, widget.double_click_interval) {
+                    (Some(position), Some(interval)) => {
+                        let is_double_click = is_double_press(
+                            state.previous_press,
+                            position,
+                            now,
+                            interval,
+                            0.0,
+                        );
+
+                        state.previous_press =
+                            Some((position, now, is_double_click));
+
+                        is_double_click
+                    }
+                    (Some(position), None) => {
+                        let new_click = mouse::Click::new(
+                            position,
+                            mouse::Button::Left,
+                            state.previous_click,
+                        );
 
-                state.previous_click = Some(new_click);
+                        state.previous_click = Some(new_click);
 
-                // Even if this is not a double click, but the press is nevertheless
-                // processed by us and should not be popup to parent widgets.
-                captured = true;
+                        matches!(new_click.kind(), mouse::click::Kind::Double)
+                    }
+                    (None, _) => false,
+                },
+            };
+
+            if is_double_click {
+                shell.publish(message.clone());
             }
+
+            // Even if this is not a double click, but the press is nevertheless
+            // processed by us and should not be popup to parent widgets.
+            captured = true;
         }
 
         if captured {
@@ -464,6 +1278,29 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
+    let mut released = false;
+
+    if let Some(on_any_release) = widget.on_any_release.as_ref() {
+        let button = match event {
+            Event::Mouse(mouse::Event::ButtonReleased(button)) => Some(button),
+            Event::Touch(touch::Event::FingerLifted { .. }) => {
+                Some(mouse::Button::Left)
+            }
+            _ => None,
+        };
+
+        if let Some(button) = button {
+            if let Some(position) = cursor
+                .position_in(layout.bounds())
+                .map(|position| widget.snapped(position))
+            {
+                shell.publish(on_any_release(button, position));
+            }
+
+            released = true;
+        }
+    }
+
     if let Some(message) = widget.on_release.as_ref() {
         if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. }) = event
@@ -474,11 +1311,57 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
-    if let Some(message) = widget.on_right_press.as_ref() {
+    if widget.on_right_press.is_some() || widget.on_right_double_click.is_some()
+    {
         if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) =
             event
         {
-            shell.publish(message.clone());
+            if let Some(message) = widget.on_right_press.as_ref() {
+                shell.publish(message.clone());
+            }
+
+            if let (Some(on_right_double_click), Some(position)) =
+                (widget.on_right_double_click.as_ref(), cursor_position)
+            {
+                let is_double_click = match widget.double_click_interval {
+                    Some(interval) => {
+                        let now = Instant::now();
+
+                        let is_double_click = is_double_press(
+                            state.previous_right_press,
+                            position,
+                            now,
+                            interval,
+                            0.0,
+                        );
+
+                        state.previous_right_press =
+                            Some((position, now, is_double_click));
+
+                        is_double_click
+                    }
+                    None => {
+                        let new_click = mouse::Click::new(
+                            position,
+                            mouse::Button::Right,
+                            state.previous_right_click,
+                        );
+
+                        state.previous_right_click = Some(new_click);
+
+                        matches!(new_click.kind(), mouse::click::Kind::Double)
+                    }
+                };
+
+                if is_double_click {
+                    if let Some(position) = cursor
+                        .position_in(layout.bounds())
+                        .map(|position| widget.snapped(position))
+                    {
+                        shell.publish(on_right_double_click(position));
+                    }
+                }
+            }
 
             return event::Status::Captured;
         }
@@ -495,12 +1378,14 @@ fn update<Message: Clone, Theme, Renderer>(
         }
     }
 
-    if let Some(message) = widget.on_middle_press.as_ref() {
+    if widget.on_middle_press.is_some() || widget.on_middle_drag.is_some() {
         if let Event::Mouse(mouse::Event::ButtonPressed(
             mouse::Button::Middle,
         )) = event
         {
-            shell.publish(message.clone());
+            if let Some(message) = widget.on_middle_press.as_ref() {
+                shell.publish(message.clone());
+            }
 
             return event::Status::Captured;
         }
@@ -519,11 +1404,247 @@ fn update<Message: Clone, Theme, Renderer>(
 
     if let Some(on_scroll) = widget.on_scroll.as_ref() {
         if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
-            shell.publish(on_scroll(delta));
+            shell.publish(on_scroll.get(delta, widget.scroll_line_height));
 
             return event::Status::Captured;
         }
     }
 
-    event::Status::Ignored
+    if released {
+        event::Status::Captured
+    } else {
+        event::Status::Ignored
+    }
+}
+
+/// The axis along which the [`segments`](MouseArea::segments) of a
+/// [`MouseArea`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    /// Segments are laid out from left to right.
+    #[default]
+    Horizontal,
+    /// Segments are laid out from top to bottom.
+    Vertical,
+}
+
+/// A step of a left button or touch interaction with a [`MouseArea`].
+///
+/// See [`MouseArea::on_gesture`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// The interaction started at the given position.
+    Start(Point),
+    /// The cursor moved to the given position during the interaction.
+    Update(Point),
+    /// The interaction finished over the area at the given position.
+    End(Point),
+    /// The interaction finished outside of the area or was interrupted.
+    Cancel,
+}
+
+/// Produces an [`Operation`] that returns the [`State`] of the [`MouseArea`]
+/// with the given [`Id`].
+pub fn state(id: impl Into<Id>) -> impl Operation<State> {
+    struct QueryState {
+        target: Id,
+        state: Option<State>,
+    }
+
+    impl Operation<State> for QueryState {
+        fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&Id>) {
+            if id == Some(&self.target) {
+                if let Some(state) = state.downcast_ref::<State>() {
+                    self.state = Some(state.clone());
+                }
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<State>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> operation::Outcome<State> {
+            if let Some(state) = &self.state {
+                operation::Outcome::Some(state.clone())
+            } else {
+                operation::Outcome::None
+            }
+        }
+    }
+
+    QueryState {
+        target: id.into(),
+        state: None,
+    }
+}
+
+/// The kind of device that produced a press on a [`MouseArea`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    /// A mouse, or any other pointing device reported as one.
+    Mouse,
+    /// A finger, or any other touch input such as a pen.
+    Touch,
+}
+
+/// The possible status of a [`MouseArea`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`MouseArea`] is not being interacted with.
+    Active,
+    /// The [`MouseArea`] is being hovered.
+    Hovered,
+    /// The [`MouseArea`] is being pressed.
+    Pressed,
+}
+
+/// The appearance of a mouse area.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Style {
+    /// The [`Background`] drawn behind the content, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] drawn around the content.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`MouseArea`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`MouseArea`].
+///
+/// This is just a boxed closure: `Fn(&Theme, Status) -> Style`.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`MouseArea`], which draws nothing.
+pub fn default(_theme: &Theme, _status: Status) -> Style {
+    Style::default()
+}
+
+/// A style that highlights a [`MouseArea`] with the primary color of the
+/// [`Theme`] while it is hovered or pressed.
+pub fn highlight(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Active => Style::default(),
+        Status::Hovered => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.2).into(),
+            ),
+            ..Style::default()
+        },
+        Status::Pressed => Style {
+            background: Some(
+                palette.primary.weak.color.scale_alpha(0.4).into(),
+            ),
+            ..Style::default()
+        },
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::*;
+    use crate::test::Simulator;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(f32),
+        Enter,
+        Exit,
+        DoubleClick,
+        RightDoubleClick(Point),
+    }
+
+    #[test]
+    fn enter_and_exit_fire_while_the_content_captures_moves() {
+        let mut simulator = Simulator::<_, Theme>::new(
+            MouseArea::new(
+                iced::widget::slider(0.0..=100.0, 50.0, Message::Changed)
+                    .width(100),
+            )
+            .on_enter(Message::Enter)
+            .on_exit(Message::Exit),
+            (200.0, 100.0),
+        );
+
+        assert_eq!(simulator.move_to((75.0, 5.0)), vec![Message::Enter]);
+        assert!(matches!(
+            simulator.press(mouse::Button::Left).as_slice(),
+            [Message::Changed(_)]
+        ));
+
+        let messages = simulator.move_to((150.0, 5.0));
+
+        assert!(matches!(messages.first(), Some(Message::Changed(_))));
+        assert_eq!(messages.last(), Some(&Message::Exit));
+    }
+
+    fn double_clickable<'a>() -> MouseArea<'a, Message, Theme, ()> {
+        MouseArea::new(iced::widget::Space::new(100, 100))
+            .on_double_click(Message::DoubleClick)
+            .on_right_double_click(Message::RightDoubleClick)
+    }
+
+    fn click(
+        simulator: &mut Simulator<'_, Message, Theme>,
+        button: mouse::Button,
+    ) -> Vec<Message> {
+        let mut messages = simulator.press(button);
+        messages.extend(simulator.release(button));
+        messages
+    }
+
+    #[test]
+    fn left_clicks_do_not_break_right_double_clicks() {
+        let mut simulator =
+            Simulator::<_, Theme>::new(double_clickable(), (200.0, 200.0));
+
+        let _ = simulator.move_to((10.0, 10.0));
+
+        assert_eq!(click(&mut simulator, mouse::Button::Right), vec![]);
+        assert_eq!(click(&mut simulator, mouse::Button::Left), vec![]);
+        assert_eq!(
+            click(&mut simulator, mouse::Button::Right),
+            vec![Message::RightDoubleClick(Point::new(10.0, 10.0))]
+        );
+    }
+
+    #[test]
+    fn left_clicks_do_not_complete_right_double_clicks() {
+        let mut simulator =
+            Simulator::<_, Theme>::new(double_clickable(), (200.0, 200.0));
+
+        let _ = simulator.move_to((10.0, 10.0));
+
+        assert_eq!(click(&mut simulator, mouse::Button::Left), vec![]);
+        assert_eq!(click(&mut simulator, mouse::Button::Right), vec![]);
+    }
 }
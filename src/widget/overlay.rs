@@ -1 +1,227 @@
+//! Display interactive elements on top of other widgets.
+//!
+//! Besides the dropdown [`menu`] used by the sweetened
+//! [`PickList`](crate::widget::pick_list::PickList), this module exposes
+//! [`positioned`], which places any [`Element`] next to an anchor and keeps it
+//! within the viewport. Use it to build your own dropdowns, popovers or
+//! tooltips without reimplementing the positioning math.
+use iced::advanced::widget::{Operation, Tree, Widget};
+use iced::advanced::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
+};
+use iced::event::{self, Event};
+use iced::{Element, Point, Rectangle, Size, Vector};
+
 pub mod menu;
+
+/// The side of the anchor where a [`positioned`] overlay is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// Above the anchor.
+    Top,
+    /// To the left of the anchor.
+    Left,
+    /// To the right of the anchor.
+    Right,
+}
+
+impl Placement {
+    /// Returns the [`Placement`] on the opposite side of the anchor.
+    pub fn opposite(self) -> Self {
+        match self {
+            Placement::Bottom => Placement::Top,
+            Placement::Top => Placement::Bottom,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    /// Returns the space available on this side of the `anchor`, along the
+    /// axis the overlay grows in.
+    fn space(self, anchor: Rectangle, viewport: Size) -> f32 {
+        match self {
+            Placement::Bottom => viewport.height - (anchor.y + anchor.height),
+            Placement::Top => anchor.y,
+            Placement::Left => anchor.x,
+            Placement::Right => viewport.width - (anchor.x + anchor.width),
+        }
+    }
+
+    /// Returns the main size of `size` along the axis of this side.
+    fn extent(self, size: Size) -> f32 {
+        match self {
+            Placement::Bottom | Placement::Top => size.height,
+            Placement::Left | Placement::Right => size.width,
+        }
+    }
+}
+
+/// Computes the top-left position of an overlay of the given `size` next to
+/// `anchor`, in absolute coordinates.
+///
+/// The overlay is centered on the anchor along the cross axis. If it does not
+/// fit on the requested side of the anchor but there is more room on the
+/// opposite side, the [`Placement`] is flipped. The result is then clamped so
+/// that the overlay stays within the `viewport` whenever possible.
+pub fn place(
+    anchor: Rectangle,
+    size: Size,
+    viewport: Size,
+    placement: Placement,
+) -> Point {
+    let placement = if placement.space(anchor, viewport)
+        < placement.extent(size)
+        && placement.opposite().space(anchor, viewport)
+            > placement.space(anchor, viewport)
+    {
+        placement.opposite()
+    } else {
+        placement
+    };
+
+    let center_x = anchor.center_x() - size.width / 2.0;
+    let center_y = anchor.center_y() - size.height / 2.0;
+
+    let position = match placement {
+        Placement::Bottom => Point::new(center_x, anchor.y + anchor.height),
+        Placement::Top => Point::new(center_x, anchor.y - size.height),
+        Placement::Left => Point::new(anchor.x - size.width, center_y),
+        Placement::Right => Point::new(anchor.x + anchor.width, center_y),
+    };
+
+    Point::new(
+        position.x.min(viewport.width - size.width).max(0.0),
+        position.y.min(viewport.height - size.height).max(0.0),
+    )
+}
+
+/// Turns the `content` into an overlay [`overlay::Element`] placed next to
+/// the `anchor` bounds.
+///
+/// The `anchor` must be in absolute coordinates, i.e. the bounds of the
+/// widget layout plus the translation received by
+/// [`Widget::overlay`](iced::advanced::Widget::overlay). The `tree` must be
+/// the [`Tree`] of the `content`. See [`place`] for the placement rules.
+pub fn positioned<'a, 'b, Message, Theme, Renderer>(
+    anchor: Rectangle,
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    placement: Placement,
+) -> overlay::Element<'b, Message, Theme, Renderer>
+where
+    Message: 'b,
+    Theme: 'b,
+    Renderer: renderer::Renderer + 'b,
+{
+    overlay::Element::new(Box::new(Positioned {
+        anchor,
+        content: content.as_widget_mut(),
+        tree,
+        placement,
+        is_interactive: true,
+    }))
+}
+
+/// An overlay placed next to an anchor with the rules of [`place`].
+pub(crate) struct Positioned<'a, 'b, Message, Theme, Renderer> {
+    pub(crate) anchor: Rectangle,
+    pub(crate) content: &'b mut (dyn Widget<Message, Theme, Renderer> + 'a),
+    pub(crate) tree: &'b mut Tree,
+    pub(crate) placement: Placement,
+    /// Whether the overlay is hit by the cursor, hiding it from the widgets
+    /// underneath.
+    pub(crate) is_interactive: bool,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    iced::advanced::Overlay<Message, Theme, Renderer>
+    for Positioned<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let node = self.content.layout(self.tree, renderer, &limits);
+        let position = place(self.anchor, node.size(), bounds, self.placement);
+
+        node.move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content.draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.operate(self.tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        self.content.on_event(
+            self.tree, event, layout, cursor, renderer, clipboard, shell,
+            &bounds,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        self.is_interactive && layout.bounds().contains(cursor_position)
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        self.content
+            .overlay(self.tree, layout, renderer, Vector::ZERO)
+    }
+}
@@ -23,19 +23,24 @@
 // IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 use iced::advanced::text::{self, Text};
-use iced::advanced::widget::Tree;
+use iced::advanced::widget::{operation, tree, Tree};
 use iced::advanced::{layout, mouse, overlay, renderer, Clipboard, Layout};
 use iced::advanced::{Shell, Widget};
 use iced::alignment;
 use iced::border::{self, Border};
 use iced::event::{self, Event};
+use iced::keyboard::{self, key};
+use iced::time::{Duration, Instant};
 use iced::touch;
-use iced::widget::scrollable::{self, Scrollable};
+use iced::widget::scrollable::{self, AbsoluteOffset, Scrollable};
+use iced::window;
 use iced::{
     Background, Color, Element, Length, Padding, Pixels, Point, Rectangle,
-    Size, Theme, Vector,
+    Size, Theme,
 };
 
+use crate::widget::overlay::{place, Placement};
+
 /// A list of selectable options.
 #[allow(missing_debug_implementations)]
 pub struct Menu<
@@ -55,7 +60,12 @@ pub struct Menu<
     disabled: Option<Vec<bool>>,
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
+    on_middle_selected: Option<&'a dyn Fn(T) -> Message>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    format: Option<&'a dyn Fn(&T) -> String>,
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    loading: Option<&'a str>,
+    animation: Option<Animation>,
     width: f32,
     padding: Padding,
     text_size: Option<Pixels>,
@@ -91,7 +101,12 @@ where
             disabled,
             hovered_option,
             on_selected: Box::new(on_selected),
+            on_middle_selected: None,
             on_option_hovered,
+            format: None,
+            items: Vec::new(),
+            loading: None,
+            animation: None,
             width: 0.0,
             padding: Padding::ZERO,
             text_size: None,
@@ -102,6 +117,55 @@ where
         }
     }
 
+    /// Sets the message to produce when an option is selected with the middle
+    /// mouse button.
+    pub fn on_middle_selected(
+        mut self,
+        on_middle_selected: &'a dyn Fn(T) -> Message,
+    ) -> Self {
+        self.on_middle_selected = Some(on_middle_selected);
+        self
+    }
+
+    /// Sets the function used to produce the label of an option.
+    ///
+    /// By default, options are labeled with their [`ToString`] implementation.
+    pub fn format(mut self, format: &'a dyn Fn(&T) -> String) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the elements displayed for the options, in the same order,
+    /// instead of their labels.
+    ///
+    /// Each row is as tall as its element plus the [`Padding`] of the
+    /// [`Menu`]. The elements are only drawn, with the text color of the
+    /// status of their option; the [`Menu`] keeps handling hovering and
+    /// selection.
+    pub fn items(
+        mut self,
+        items: Vec<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Shows a single, non-selectable row with the given label instead of the
+    /// options, e.g. while they are being fetched.
+    pub fn loading(mut self, label: &'a str) -> Self {
+        self.loading = Some(label);
+        self
+    }
+
+    /// Animates the [`Menu`] with the given [`Animation`], expanding from its
+    /// target and fading in while opening, and the reverse while closing.
+    ///
+    /// A closing [`Menu`] is only drawn and lets every event through.
+    pub fn animate(mut self, animation: Animation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: f32) -> Self {
         self.width = width;
@@ -164,6 +228,7 @@ where
 #[derive(Debug)]
 pub struct State {
     tree: Tree,
+    last_redraw: Option<Instant>,
 }
 
 impl State {
@@ -171,8 +236,15 @@ impl State {
     pub fn new() -> Self {
         Self {
             tree: Tree::empty(),
+            last_redraw: None,
         }
     }
+
+    /// Forgets the scroll position of the [`Menu`], e.g. because its options
+    /// changed.
+    pub fn reset_scroll(&mut self) {
+        self.tree = Tree::empty();
+    }
 }
 
 impl Default for State {
@@ -188,25 +260,57 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
-    /// Calculate the index of an option based on a cursor position within the list bounds
+    /// Calculate the height of a single option
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+
+    /// Calculate the index of the option under the cursor, if any
     fn option_index_at(
         &self,
-        cursor_position: Point,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
     ) -> Option<usize> {
-        let text_size =
-            self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size))
-                + self.padding.vertical();
+        let cursor_position = cursor.position_in(layout.bounds())?;
 
-        let index = (cursor_position.y / option_height) as usize;
+        if !self.items.is_empty() {
+            let cursor_position = cursor.position()?;
 
-        if index < self.options.len() {
-            Some(index)
-        } else {
-            None
+            return layout
+                .children()
+                .position(|row| row.bounds().contains(cursor_position));
+        }
+
+        let index = (cursor_position.y / self.option_height(renderer)) as usize;
+
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Calculate the bounds of the option at the given index
+    fn row_bounds(
+        &self,
+        layout: Layout<'_>,
+        index: usize,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        if !self.items.is_empty() {
+            return layout.children().nth(index).map(|row| row.bounds());
         }
+
+        let bounds = layout.bounds();
+        let option_height = self.option_height(renderer);
+
+        (index < self.options.len()).then_some(Rectangle {
+            x: bounds.x,
+            y: bounds.y + option_height * index as f32,
+            width: bounds.width,
+            height: option_height,
+        })
     }
 
     /// Check if an option at the given index is disabled
@@ -217,6 +321,59 @@ where
             .copied()
             .unwrap_or(false)
     }
+
+    /// Find the first enabled option starting at `from` and walking forward
+    fn next_enabled(&self, from: usize) -> Option<usize> {
+        (from..self.options.len()).find(|&index| !self.is_disabled(index))
+    }
+
+    /// Find the first enabled option starting at `from` and walking backward
+    fn previous_enabled(&self, from: usize) -> Option<usize> {
+        (0..=from.min(self.options.len().checked_sub(1)?))
+            .rev()
+            .find(|&index| !self.is_disabled(index))
+    }
+
+    /// Calculate the option to highlight when the given key is pressed,
+    /// moving by `page` options on PageUp/PageDown
+    fn navigate(&self, key: key::Named, page: usize) -> Option<usize> {
+        let last = self.options.len().checked_sub(1)?;
+        let current = *self.hovered_option;
+
+        match key {
+            key::Named::ArrowDown => {
+                self.next_enabled(current.map_or(0, |index| index + 1))
+            }
+            key::Named::ArrowUp => match current {
+                Some(index) => self.previous_enabled(index.checked_sub(1)?),
+                None => self.previous_enabled(last),
+            },
+            key::Named::Home => self.next_enabled(0),
+            key::Named::End => self.previous_enabled(last),
+            key::Named::PageDown => {
+                let from = current.map_or(0, |index| (index + page).min(last));
+
+                self.next_enabled(from)
+                    .or_else(|| self.previous_enabled(from))
+            }
+            key::Named::PageUp => {
+                let from =
+                    current.map_or(0, |index| index.saturating_sub(page));
+
+                self.previous_enabled(from)
+                    .or_else(|| self.next_enabled(from))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The local state of a [`List`].
+#[derive(Debug, Default)]
+struct ListState {
+    /// The vertical offset the menu should scroll to in order to reveal an
+    /// option highlighted with the keyboard.
+    scroll_to: Option<f32>,
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -226,7 +383,12 @@ where
 {
     position: Point,
     state: &'a mut Tree,
+    last_redraw: &'a mut Option<Instant>,
+    animation: Option<Animation>,
+    /// How far the [`Menu`] is open, from `0.0` to `1.0`.
+    openness: f32,
     list: Scrollable<'a, Message, Theme, Renderer>,
+    list_id: scrollable::Id,
     width: f32,
     target_height: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
@@ -253,7 +415,12 @@ where
             disabled,
             hovered_option,
             on_selected,
+            on_middle_selected,
             on_option_hovered,
+            format,
+            items,
+            loading,
+            animation,
             width,
             padding,
             font,
@@ -263,26 +430,42 @@ where
             class,
         } = menu;
 
+        let openness = animation.map_or(1.0, |animation| {
+            animation.openness(state.last_redraw.unwrap_or(animation.opened_at))
+        });
+
+        let list_id = scrollable::Id::unique();
+
         let list = Scrollable::new(List {
             options,
             disabled,
             hovered_option,
             on_selected,
+            on_middle_selected,
             on_option_hovered,
+            format,
+            items,
+            loading,
             font,
             text_size,
             text_line_height,
             text_shaping,
             padding,
+            alpha: openness,
             class,
-        });
+        })
+        .id(list_id.clone());
 
         state.tree.diff(&list as &dyn Widget<_, _, _>);
 
         Self {
             position,
             state: &mut state.tree,
+            last_redraw: &mut state.last_redraw,
+            animation,
+            openness,
             list,
+            list_id,
             width,
             target_height,
             class,
@@ -298,31 +481,53 @@ where
     Renderer: text::Renderer,
 {
     fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let anchor = Rectangle::new(
+            self.position,
+            Size::new(self.width, self.target_height),
+        );
+
         let space_below =
             bounds.height - (self.position.y + self.target_height);
         let space_above = self.position.y;
 
+        // Open below the target if the whole menu fits there, otherwise on the
+        // side with the most room, clamping the height to the space available.
+        let height = self
+            .list
+            .layout(
+                self.state,
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(bounds.width, f32::INFINITY),
+                )
+                .width(self.width),
+            )
+            .size()
+            .height;
+
+        let placement = if height <= space_below || space_below >= space_above {
+            Placement::Bottom
+        } else {
+            Placement::Top
+        };
+
         let limits = layout::Limits::new(
             Size::ZERO,
             Size::new(
-                bounds.width - self.position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
+                bounds.width,
+                match placement {
+                    Placement::Top => space_above,
+                    _ => space_below,
                 },
             ),
         )
         .width(self.width);
 
         let node = self.list.layout(self.state, renderer, &limits);
-        let size = node.size();
+        let position = place(anchor, node.size(), bounds, placement);
 
-        node.move_to(if space_below > space_above {
-            self.position + Vector::new(0.0, self.target_height)
-        } else {
-            self.position - Vector::new(0.0, size.height)
-        })
+        node.move_to(position)
     }
 
     fn on_event(
@@ -336,10 +541,71 @@ where
     ) -> event::Status {
         let bounds = layout.bounds();
 
-        self.list.on_event(
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if let Some(animation) = self.animation {
+                *self.last_redraw = Some(now);
+
+                if !animation.is_settled(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        if self.is_closing() {
+            return event::Status::Ignored;
+        }
+
+        // The scrollable captures every touch, even outside of its bounds,
+        // which would keep a tap outside of the menu from dismissing it.
+        if let Event::Touch(touch::Event::FingerPressed { position, .. }) =
+            event
+        {
+            if !bounds.contains(position) {
+                return event::Status::Ignored;
+            }
+        }
+
+        let cursor = match self.revealed(bounds) {
+            Some(revealed) if !cursor.is_over(revealed) => {
+                // Presses on the part of the menu that is not revealed yet
+                // must neither select an option nor fall through and close it
+                if let Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. }) = event
+                {
+                    if cursor.is_over(bounds) {
+                        return event::Status::Captured;
+                    }
+                }
+
+                mouse::Cursor::Unavailable
+            }
+            _ => cursor,
+        };
+
+        let status = self.list.on_event(
             self.state, event, layout, cursor, renderer, clipboard, shell,
             &bounds,
-        )
+        );
+
+        let scroll_to = self.state.children[0]
+            .state
+            .downcast_mut::<ListState>()
+            .scroll_to
+            .take();
+
+        if let Some(y) = scroll_to {
+            self.list.operate(
+                self.state,
+                layout,
+                renderer,
+                &mut operation::scrollable::scroll_to(
+                    self.list_id.clone().into(),
+                    AbsoluteOffset { x: 0.0, y },
+                ),
+            );
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -349,6 +615,17 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.is_closing() {
+            return mouse::Interaction::default();
+        }
+
+        let cursor = match self.revealed(layout.bounds()) {
+            Some(revealed) if !cursor.is_over(revealed) => {
+                mouse::Cursor::Unavailable
+            }
+            _ => cursor,
+        };
+
         self.list
             .mouse_interaction(self.state, layout, cursor, viewport, renderer)
     }
@@ -363,20 +640,161 @@ where
     ) {
         let bounds = layout.bounds();
 
-        let style = Catalog::style(theme, self.class);
+        let style = fade(Catalog::style(theme, self.class), self.openness);
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds,
-                border: style.border,
-                ..renderer::Quad::default()
-            },
-            style.background,
-        );
+        let draw = |renderer: &mut Renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
 
-        self.list.draw(
-            self.state, renderer, theme, defaults, layout, cursor, &bounds,
-        );
+            self.list.draw(
+                self.state, renderer, theme, defaults, layout, cursor, &bounds,
+            );
+        };
+
+        if let Some(revealed) = self.revealed(bounds) {
+            renderer.with_layer(revealed, draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        !self.is_closing() && layout.bounds().contains(cursor_position)
+    }
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// Returns whether the [`Menu`] is being closed.
+    fn is_closing(&self) -> bool {
+        self.animation.is_some_and(Animation::is_closing)
+    }
+
+    /// Returns the part of the given menu `bounds` revealed so far by the
+    /// animation, or `None` if the menu is fully revealed.
+    fn revealed(&self, bounds: Rectangle) -> Option<Rectangle> {
+        if self.openness >= 1.0 {
+            return None;
+        }
+
+        let height = bounds.height * self.openness;
+
+        // Expand away from the target, i.e. upwards when placed on top
+        let y = if bounds.y < self.position.y {
+            bounds.y + bounds.height - height
+        } else {
+            bounds.y
+        };
+
+        Some(Rectangle {
+            y,
+            height,
+            ..bounds
+        })
+    }
+}
+
+/// The animation of a [`Menu`] expanding from its target and fading in as it
+/// opens, then playing backwards as it closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Animation {
+    opened_at: Instant,
+    closed_at: Option<Instant>,
+}
+
+impl Animation {
+    /// The time it takes a [`Menu`] to fully open or close.
+    pub const DURATION: Duration = Duration::from_millis(150);
+
+    /// Starts opening a [`Menu`] at the given [`Instant`].
+    pub fn open(at: Instant) -> Self {
+        Self {
+            opened_at: at,
+            closed_at: None,
+        }
+    }
+
+    /// Starts closing the [`Menu`] at the given [`Instant`], from however far
+    /// it got to open.
+    #[must_use]
+    pub fn close(self, at: Instant) -> Self {
+        Self {
+            closed_at: Some(self.closed_at.unwrap_or(at.max(self.opened_at))),
+            ..self
+        }
+    }
+
+    /// Returns whether the [`Menu`] is closing, or has finished closing.
+    pub fn is_closing(self) -> bool {
+        self.closed_at.is_some()
+    }
+
+    /// Returns whether the [`Menu`] is fully open, or fully closed, at `now`.
+    pub fn is_settled(self, now: Instant) -> bool {
+        let progress = self.progress(now);
+
+        if self.is_closing() {
+            progress <= 0.0
+        } else {
+            progress >= 1.0
+        }
+    }
+
+    /// Returns how far the [`Menu`] is open at `now`, from `0.0` to `1.0`.
+    pub fn openness(self, now: Instant) -> f32 {
+        let t = self.progress(now);
+
+        // Ease out cubic
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    /// Returns the linear progress of the animation at `now`.
+    fn progress(self, now: Instant) -> f32 {
+        let elapsed = |from: Instant, to: Instant| {
+            to.saturating_duration_since(from).as_secs_f32()
+                / Self::DURATION.as_secs_f32()
+        };
+
+        match self.closed_at {
+            None => elapsed(self.opened_at, now).min(1.0),
+            Some(closed_at) => (elapsed(self.opened_at, closed_at).min(1.0)
+                - elapsed(closed_at, now))
+            .max(0.0),
+        }
+    }
+}
+
+/// Scales the alpha of every color of the given [`Style`] by `alpha`.
+fn fade(style: Style, alpha: f32) -> Style {
+    if alpha >= 1.0 {
+        return style;
+    }
+
+    Style {
+        background: style.background.scale_alpha(alpha),
+        border: Border {
+            color: style.border.color.scale_alpha(alpha),
+            ..style.border
+        },
+        text_color: style.text_color.scale_alpha(alpha),
+        selected_text_color: style.selected_text_color.scale_alpha(alpha),
+        selected_background: style.selected_background.scale_alpha(alpha),
+        disabled_text_color: style.disabled_text_color.scale_alpha(alpha),
+        disabled_background: style.disabled_background.scale_alpha(alpha),
     }
 }
 
@@ -389,12 +807,17 @@ where
     disabled: Option<Vec<bool>>,
     hovered_option: &'a mut Option<usize>,
     on_selected: Box<dyn FnMut(T) -> Message + 'a>,
+    on_middle_selected: Option<&'a dyn Fn(T) -> Message>,
     on_option_hovered: Option<&'a dyn Fn(T) -> Message>,
+    format: Option<&'a dyn Fn(&T) -> String>,
+    items: Vec<Element<'a, Message, Theme, Renderer>>,
+    loading: Option<&'a str>,
     padding: Padding,
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    alpha: f32,
     class: &'a <Theme as Catalog>::Class<'b>,
 }
 
@@ -405,6 +828,14 @@ where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<ListState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ListState::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: Length::Fill,
@@ -412,24 +843,89 @@ where
         }
     }
 
+    fn children(&self) -> Vec<Tree> {
+        self.items.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.items);
+    }
+
     fn layout(
         &self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
         use std::f32;
 
+        if self.loading.is_none() && !self.items.is_empty() {
+            let width = limits
+                .resolve(Length::Fill, Length::Shrink, Size::ZERO)
+                .width;
+
+            let item_limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(width, f32::INFINITY),
+            )
+            .width(width)
+            .shrink(self.padding);
+
+            let mut height = 0.0;
+
+            let rows = self
+                .items
+                .iter()
+                .zip(tree.children.iter_mut())
+                .map(|(item, tree)| {
+                    let node = item
+                        .as_widget()
+                        .layout(tree, renderer, &item_limits)
+                        .move_to(Point::new(
+                            self.padding.left,
+                            self.padding.top,
+                        ));
+
+                    let row_height =
+                        node.size().height + self.padding.vertical();
+
+                    let row = layout::Node::with_children(
+                        Size::new(width, row_height),
+                        vec![node],
+                    )
+                    .move_to(Point::new(0.0, height));
+
+                    height += row_height;
+
+                    row
+                })
+                .collect();
+
+            let size = limits.resolve(
+                Length::Fill,
+                Length::Shrink,
+                Size::new(0.0, height),
+            );
+
+            return layout::Node::with_children(size, rows);
+        }
+
         let text_size =
             self.text_size.unwrap_or_else(|| renderer.default_size());
 
         let text_line_height = self.text_line_height.to_absolute(text_size);
 
+        let rows = if self.loading.is_some() {
+            1
+        } else {
+            self.options.len()
+        };
+
         let size = {
             let intrinsic = Size::new(
                 0.0,
                 (f32::from(text_line_height) + self.padding.vertical())
-                    * self.options.len() as f32,
+                    * rows as f32,
             );
 
             limits.resolve(Length::Fill, Length::Shrink, intrinsic)
@@ -440,28 +936,116 @@ where
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
+        if self.loading.is_some() {
+            // Nothing can be selected while loading, but presses on the menu
+            // must not fall through and close it.
+            return match event {
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if cursor.is_over(layout.bounds()) =>
+                {
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            };
+        }
+
         match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Enter),
+                ..
+            }) => {
+                if let Some(index) = *self.hovered_option {
+                    if !self.is_disabled(index) {
+                        if let Some(option) = self.options.get(index) {
+                            shell.publish((self.on_selected)(option.clone()));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                // Rows may have different heights when rendered as elements,
+                // so a page is measured with their average height
+                let row_height =
+                    layout.bounds().height / self.options.len().max(1) as f32;
+                let page =
+                    ((viewport.height / row_height).floor() as usize).max(1);
+
+                if let Some(index) = self.navigate(named, page) {
+                    if *self.hovered_option != Some(index) {
+                        if let Some(on_option_hovered) = self.on_option_hovered
+                        {
+                            if let Some(option) = self.options.get(index) {
+                                shell
+                                    .publish(on_option_hovered(option.clone()));
+                            }
+                        }
+                        *self.hovered_option = Some(index);
+                    }
+
+                    // Scroll just enough to keep the highlighted option visible
+                    if let Some(row) = self.row_bounds(layout, index, renderer)
+                    {
+                        let bounds = layout.bounds();
+
+                        let scroll_to = if row.y < viewport.y {
+                            Some(row.y - bounds.y)
+                        } else if row.y + row.height
+                            > viewport.y + viewport.height
+                        {
+                            Some(
+                                row.y + row.height - viewport.height - bounds.y,
+                            )
+                        } else {
+                            None
+                        };
+
+                        if scroll_to.is_some() {
+                            state.state.downcast_mut::<ListState>().scroll_to =
+                                scroll_to;
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if let Some(cursor_position) =
-                    cursor.position_in(layout.bounds())
+                if let Some(clicked_index) =
+                    self.option_index_at(layout, cursor, renderer)
                 {
+                    if !self.is_disabled(clicked_index) {
+                        if let Some(option) = self.options.get(clicked_index) {
+                            shell.publish((self.on_selected)(option.clone()));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => {
+                if let Some(on_middle_selected) = self.on_middle_selected {
                     if let Some(clicked_index) =
-                        self.option_index_at(cursor_position, renderer)
+                        self.option_index_at(layout, cursor, renderer)
                     {
                         if !self.is_disabled(clicked_index) {
                             if let Some(option) =
                                 self.options.get(clicked_index)
                             {
-                                shell.publish((self.on_selected)(
+                                shell.publish(on_middle_selected(
                                     option.clone(),
                                 ));
                             }
@@ -471,53 +1055,41 @@ where
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                if let Some(cursor_position) =
-                    cursor.position_in(layout.bounds())
+                if let Some(new_hovered_option) =
+                    self.option_index_at(layout, cursor, renderer)
                 {
-                    if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
-                    {
-                        if !self.is_disabled(new_hovered_option) {
-                            if let Some(on_option_hovered) =
-                                self.on_option_hovered
+                    if !self.is_disabled(new_hovered_option) {
+                        if let Some(on_option_hovered) = self.on_option_hovered
+                        {
+                            if *self.hovered_option != Some(new_hovered_option)
                             {
-                                if *self.hovered_option
-                                    != Some(new_hovered_option)
+                                if let Some(option) =
+                                    self.options.get(new_hovered_option)
                                 {
-                                    if let Some(option) =
-                                        self.options.get(new_hovered_option)
-                                    {
-                                        shell.publish(on_option_hovered(
-                                            option.clone(),
-                                        ));
-                                    }
+                                    shell.publish(on_option_hovered(
+                                        option.clone(),
+                                    ));
                                 }
                             }
-                            *self.hovered_option = Some(new_hovered_option);
                         }
-                        return event::Status::Captured;
+                        *self.hovered_option = Some(new_hovered_option);
                     }
+                    return event::Status::Captured;
                 }
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if let Some(cursor_position) =
-                    cursor.position_in(layout.bounds())
+                if let Some(new_hovered_option) =
+                    self.option_index_at(layout, cursor, renderer)
                 {
-                    if let Some(new_hovered_option) =
-                        self.option_index_at(cursor_position, renderer)
-                    {
-                        if !self.is_disabled(new_hovered_option) {
-                            *self.hovered_option = Some(new_hovered_option);
-                            if let Some(option) =
-                                self.options.get(new_hovered_option)
-                            {
-                                shell.publish((self.on_selected)(
-                                    option.clone(),
-                                ));
-                            }
+                    if !self.is_disabled(new_hovered_option) {
+                        *self.hovered_option = Some(new_hovered_option);
+                        if let Some(option) =
+                            self.options.get(new_hovered_option)
+                        {
+                            shell.publish((self.on_selected)(option.clone()));
                         }
-                        return event::Status::Captured;
                     }
+                    return event::Status::Captured;
                 }
             }
             _ => {}
@@ -534,13 +1106,15 @@ where
         _viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        if let Some(cursor_position) = cursor.position_in(layout.bounds()) {
-            if let Some(hovered_index) =
-                self.option_index_at(cursor_position, renderer)
-            {
-                if !self.is_disabled(hovered_index) {
-                    return mouse::Interaction::Pointer;
-                }
+        if self.loading.is_some() {
+            return mouse::Interaction::default();
+        }
+
+        if let Some(hovered_index) =
+            self.option_index_at(layout, cursor, renderer)
+        {
+            if !self.is_disabled(hovered_index) {
+                return mouse::Interaction::Pointer;
             }
         }
 
@@ -549,22 +1123,113 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        state: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let style = Catalog::style(theme, self.class);
+        let style = fade(Catalog::style(theme, self.class), self.alpha);
         let bounds = layout.bounds();
 
         let text_size =
             self.text_size.unwrap_or_else(|| renderer.default_size());
-        let option_height =
-            f32::from(self.text_line_height.to_absolute(text_size))
-                + self.padding.vertical();
+        let option_height = self.option_height(renderer);
+
+        if let Some(label) = self.loading {
+            renderer.fill_text(
+                Text {
+                    content: label.to_owned(),
+                    bounds: Size::new(f32::INFINITY, option_height),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: text::Wrapping::default(),
+                },
+                Point::new(
+                    bounds.x + self.padding.left,
+                    bounds.y + option_height / 2.0,
+                ),
+                style.disabled_text_color,
+                *viewport,
+            );
+
+            return;
+        }
+
+        let draw_background =
+            |renderer: &mut Renderer, bounds: Rectangle, background| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + style.border.width,
+                            width: bounds.width - style.border.width * 2.0,
+                            ..bounds
+                        },
+                        border: border::rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            };
+
+        if !self.items.is_empty() {
+            for (i, ((item, tree), row)) in self
+                .items
+                .iter()
+                .zip(&state.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let row_bounds = row.bounds();
+
+                if row_bounds.intersection(viewport).is_none() {
+                    continue;
+                }
+
+                let is_selected = *self.hovered_option == Some(i);
+                let is_disabled = self.is_disabled(i);
+
+                let text_color = if is_disabled {
+                    draw_background(
+                        renderer,
+                        row_bounds,
+                        style.disabled_background,
+                    );
+
+                    style.disabled_text_color
+                } else if is_selected {
+                    draw_background(
+                        renderer,
+                        row_bounds,
+                        style.selected_background,
+                    );
+
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                };
+
+                if let Some(item_layout) = row.children().next() {
+                    item.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style { text_color },
+                        item_layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            }
+
+            return;
+        }
 
         let offset = viewport.y - bounds.y;
         let start = (offset / option_height) as usize;
@@ -575,12 +1240,7 @@ where
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
             let is_selected = *self.hovered_option == Some(i);
-            let is_disabled = self
-                .disabled
-                .as_ref()
-                .and_then(|d| d.get(i))
-                .copied()
-                .unwrap_or(false);
+            let is_disabled = self.is_disabled(i);
 
             let bounds = Rectangle {
                 x: bounds.x,
@@ -590,36 +1250,17 @@ where
             };
 
             if is_selected && !is_disabled {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
-                            ..bounds
-                        },
-                        border: border::rounded(style.border.radius),
-                        ..renderer::Quad::default()
-                    },
-                    style.selected_background,
-                );
+                draw_background(renderer, bounds, style.selected_background);
             } else if is_disabled {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + style.border.width,
-                            width: bounds.width - style.border.width * 2.0,
-                            ..bounds
-                        },
-                        border: border::rounded(style.border.radius),
-                        ..renderer::Quad::default()
-                    },
-                    style.disabled_background,
-                );
+                draw_background(renderer, bounds, style.disabled_background);
             }
 
             renderer.fill_text(
                 Text {
-                    content: option.to_string(),
+                    content: self.format.map_or_else(
+                        || option.to_string(),
+                        |format| format(option),
+                    ),
                     bounds: Size::new(f32::INFINITY, bounds.height),
                     size: text_size,
                     line_height: self.text_line_height,
@@ -732,3 +1373,110 @@ pub fn default(theme: &Theme) -> Style {
             .into(),
     }
 }
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::Animation;
+    use crate::test::Simulator;
+    use crate::widget::pick_list::PickList;
+    use iced::keyboard::{self, key};
+    use iced::time::Instant;
+    use iced::{Length, Theme};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(usize),
+    }
+
+    fn simulator<'a>(
+        count: usize,
+        disabled: &'a [usize],
+    ) -> Simulator<'a, Message, Theme> {
+        let mut simulator = Simulator::new(
+            PickList::new(
+                (0..count).collect::<Vec<_>>(),
+                Some(|options: &[usize]| {
+                    options
+                        .iter()
+                        .map(|option| disabled.contains(option))
+                        .collect()
+                }),
+                None::<usize>,
+                Message::Selected,
+            )
+            .width(Length::Fill),
+            (200.0, 200.0),
+        );
+
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+
+        simulator
+    }
+
+    fn press(
+        simulator: &mut Simulator<'_, Message, Theme>,
+        key: key::Named,
+    ) -> Vec<Message> {
+        simulator
+            .press_key(keyboard::Key::Named(key), keyboard::Modifiers::empty())
+    }
+
+    #[test]
+    fn arrow_down_skips_disabled_options() {
+        let mut simulator = simulator(3, &[1]);
+
+        assert_eq!(press(&mut simulator, key::Named::ArrowDown), vec![]);
+        assert_eq!(press(&mut simulator, key::Named::ArrowDown), vec![]);
+        assert_eq!(
+            press(&mut simulator, key::Named::Enter),
+            vec![Message::Selected(2)]
+        );
+    }
+
+    #[test]
+    fn end_scrolls_the_last_option_into_view() {
+        let mut simulator = simulator(50, &[49]);
+
+        assert_eq!(press(&mut simulator, key::Named::End), vec![]);
+
+        // The last enabled option is scrolled just enough to be revealed at
+        // the bottom of the menu, which fills the space below the pick list
+        assert_eq!(simulator.click((5.0, 185.0)), vec![Message::Selected(48)]);
+    }
+
+    #[test]
+    fn enter_publishes_the_hovered_option() {
+        let mut simulator = simulator(3, &[]);
+
+        let _ = simulator.move_to((5.0, 75.0));
+
+        assert_eq!(
+            press(&mut simulator, key::Named::Enter),
+            vec![Message::Selected(1)]
+        );
+    }
+
+    #[test]
+    fn closing_plays_the_opening_backwards_from_where_it_got_to() {
+        let opened_at = Instant::now();
+        let halfway = opened_at + Animation::DURATION / 2;
+
+        let opening = Animation::open(opened_at);
+
+        assert_eq!(opening.openness(opened_at), 0.0);
+        assert!(!opening.is_settled(halfway));
+        assert!(opening.is_settled(opened_at + Animation::DURATION));
+        assert_eq!(opening.openness(opened_at + Animation::DURATION), 1.0);
+
+        let closing = opening.close(halfway);
+
+        assert!(closing.is_closing());
+        assert_eq!(closing.openness(halfway), opening.openness(halfway));
+        assert!(
+            closing.openness(halfway + Animation::DURATION / 4)
+                < closing.openness(halfway)
+        );
+        assert!(closing.is_settled(opened_at + Animation::DURATION));
+        assert_eq!(closing.openness(opened_at + Animation::DURATION), 0.0);
+    }
+}
@@ -2,10 +2,8 @@
 //!
 //! # Example
 //! ```no_run
-//! # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-//! # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-//! #
-//! use iced::widget::pick_list;
+//! use iced::Element;
+//! use sweeten::widget::pick_list;
 //!
 //! struct State {
 //!    favorite: Option<Fruit>,
@@ -34,6 +32,7 @@
 //!
 //!     pick_list(
 //!         fruits,
+//!         None::<fn(&[Fruit]) -> Vec<bool>>,
 //!         state.favorite,
 //!         Message::FruitSelected,
 //!     )
@@ -91,7 +90,9 @@ use iced::advanced::{
 use iced::alignment;
 use iced::event::{self, Event};
 use iced::keyboard;
+use iced::time::Instant;
 use iced::touch;
+use iced::window;
 use iced::{
     Background, Border, Color, Element, Length, Padding, Pixels, Point,
     Rectangle, Size, Theme, Vector,
@@ -99,6 +100,8 @@ use iced::{
 
 use std::borrow::Borrow;
 use std::f32;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 use crate::widget::overlay::menu::{self, Menu};
 
@@ -106,10 +109,8 @@ use crate::widget::overlay::menu::{self, Menu};
 ///
 /// # Example
 /// ```no_run
-/// # mod iced { pub mod widget { pub use iced_widget::*; } pub use iced_widget::Renderer; pub use iced_widget::core::*; }
-/// # pub type Element<'a, Message> = iced_widget::core::Element<'a, Message, iced_widget::Theme, iced_widget::Renderer>;
-/// #
-/// use iced::widget::pick_list;
+/// use iced::Element;
+/// use sweeten::widget::pick_list;
 ///
 /// struct State {
 ///    favorite: Option<Fruit>,
@@ -138,6 +139,7 @@ use crate::widget::overlay::menu::{self, Menu};
 ///
 ///     pick_list(
 ///         fruits,
+///         None::<fn(&[Fruit]) -> Vec<bool>>,
 ///         state.favorite,
 ///         Message::FruitSelected,
 ///     )
@@ -175,18 +177,31 @@ pub struct PickList<
     Theme = iced::Theme,
     Renderer = iced::Renderer,
 > where
-    T: ToString + PartialEq + Clone,
+    T: ToString + Clone,
     L: Borrow<[T]> + 'a,
     V: Borrow<T> + 'a,
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     on_select: Box<dyn Fn(T) -> Message + 'a>,
+    eq: Box<dyn Fn(&T, &T) -> bool + 'a>,
+    on_middle_select: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    sticky_modifiers: Option<keyboard::Modifiers>,
     on_open: Option<Message>,
     on_close: Option<Message>,
     options: L,
     disabled: Option<Box<dyn Fn(&[T]) -> Vec<bool> + 'a>>,
+    format: Option<Box<dyn Fn(&T) -> String + 'a>>,
+    view_option: Option<
+        Box<
+            dyn Fn(&T, OptionStatus) -> Element<'a, Message, Theme, Renderer>
+                + 'a,
+        >,
+    >,
     placeholder: Option<String>,
+    is_loading: bool,
+    loading_text: String,
+    is_animated: bool,
     selected: Option<V>,
     width: Length,
     padding: Padding,
@@ -194,6 +209,7 @@ pub struct PickList<
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
     font: Option<Renderer::Font>,
+    placeholder_font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
     class: <Theme as Catalog>::Class<'a>,
     menu_class: <Theme as menu::Catalog>::Class<'a>,
@@ -202,7 +218,7 @@ pub struct PickList<
 impl<'a, T, L, V, Message, Theme, Renderer>
     PickList<'a, T, L, V, Message, Theme, Renderer>
 where
-    T: ToString + PartialEq + Clone,
+    T: ToString + Clone + 'a,
     L: Borrow<[T]> + 'a,
     V: Borrow<T> + 'a,
     Message: Clone,
@@ -211,19 +227,66 @@ where
 {
     /// Creates a new [`PickList`] with the given list of options, the current
     /// selected value, and the message to produce when an option is selected.
+    ///
+    /// The `disabled` predicate receives all the options and returns whether
+    /// each of them is disabled. Its result is cached in the widget state and
+    /// only recomputed when the number of options changes, when the [`Menu`]
+    /// is opened and before stepping through the options with Ctrl and the
+    /// mouse wheel. In between, e.g. to draw a disabled selected value, the
+    /// last result is used.
     pub fn new(
         options: L,
         disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
         selected: Option<V>,
         on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self
+    where
+        T: PartialEq,
+    {
+        Self::from_eq(options, disabled, selected, on_select, T::eq)
+    }
+
+    /// Creates a new [`PickList`] like [`new`](Self::new), but identifies
+    /// options by the key returned by `key` instead of requiring
+    /// [`PartialEq`] on the options themselves.
+    ///
+    /// The selected value and the highlighted option are determined by
+    /// comparing keys, so options that would compare equal by value but are
+    /// logically distinct stay distinct.
+    pub fn with_key<K: PartialEq>(
+        options: L,
+        disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
+        selected: Option<V>,
+        on_select: impl Fn(T) -> Message + 'a,
+        key: impl Fn(&T) -> K + 'a,
+    ) -> Self {
+        Self::from_eq(options, disabled, selected, on_select, move |a, b| {
+            key(a) == key(b)
+        })
+    }
+
+    fn from_eq(
+        options: L,
+        disabled: Option<impl Fn(&[T]) -> Vec<bool> + 'a>,
+        selected: Option<V>,
+        on_select: impl Fn(T) -> Message + 'a,
+        eq: impl Fn(&T, &T) -> bool + 'a,
     ) -> Self {
         Self {
             on_select: Box::new(on_select),
+            eq: Box::new(eq),
+            on_middle_select: None,
+            sticky_modifiers: None,
             disabled: disabled.map(|f| Box::new(f) as _),
+            format: None,
+            view_option: None,
             on_open: None,
             on_close: None,
             options,
             placeholder: None,
+            is_loading: false,
+            loading_text: String::from("Loading..."),
+            is_animated: false,
             selected,
             width: Length::Shrink,
             padding: DEFAULT_PADDING,
@@ -231,6 +294,7 @@ where
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
             font: None,
+            placeholder_font: None,
             handle: Handle::default(),
             class: <Theme as Catalog>::default(),
             menu_class: <Theme as Catalog>::default_menu(),
@@ -243,6 +307,64 @@ where
         self
     }
 
+    /// Sets whether the options of the [`PickList`] are still being loaded.
+    ///
+    /// While loading, the [`Menu`] shows a single row with the loading text
+    /// instead of the options and nothing can be selected. Combine it with
+    /// [`on_open`](Self::on_open) to fetch the options lazily.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
+    /// Sets the text shown in the [`Menu`] while the [`PickList`] is
+    /// [`loading`](Self::loading). Defaults to `"Loading..."`.
+    pub fn loading_text(mut self, loading_text: impl Into<String>) -> Self {
+        self.loading_text = loading_text.into();
+        self
+    }
+
+    /// Sets whether the [`Menu`] of the [`PickList`] expands from it and
+    /// fades in when opened, and collapses and fades out when closed, instead
+    /// of appearing and disappearing at once.
+    ///
+    /// While it expands, only the options revealed so far can be hovered and
+    /// selected. While it collapses, every event goes through to the widgets
+    /// underneath.
+    pub fn animated(mut self, is_animated: bool) -> Self {
+        self.is_animated = is_animated;
+        self
+    }
+
+    /// Sets the function used to produce the label of an option, both in the
+    /// [`PickList`] itself and in its [`Menu`].
+    ///
+    /// By default, options are labeled with their [`ToString`] implementation.
+    pub fn format(mut self, format: impl Fn(&T) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the function used to render each option in the [`Menu`] as a
+    /// full [`Element`], e.g. to show a secondary line of text.
+    ///
+    /// The [`OptionStatus`] tells whether the option is hovered, currently
+    /// selected or disabled. The elements are only drawn; hovering and
+    /// clicking them is still handled by the [`Menu`]. The [`PickList`]
+    /// itself keeps displaying the selected value with its label.
+    ///
+    /// While the [`Menu`] is open, it is rebuilt on every event and redraw,
+    /// calling this function for every option each time. Keep it cheap for
+    /// long lists, or prefer [`format`](Self::format) when a label suffices.
+    pub fn view_option(
+        mut self,
+        view_option: impl Fn(&T, OptionStatus) -> Element<'a, Message, Theme, Renderer>
+            + 'a,
+    ) -> Self {
+        self.view_option = Some(Box::new(view_option));
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -282,12 +404,48 @@ where
         self
     }
 
+    /// Sets the font of the placeholder of the [`PickList`], e.g. an italic
+    /// variant to set it apart from a selected value.
+    ///
+    /// Defaults to the [`font`](Self::font) of the [`PickList`]. The color of
+    /// the placeholder is controlled by [`Style::placeholder_color`].
+    pub fn placeholder_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.placeholder_font = Some(font.into());
+        self
+    }
+
     /// Sets the [`Handle`] of the [`PickList`].
     pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
         self.handle = handle;
         self
     }
 
+    /// Sets the message that will be produced when an option is selected with
+    /// the middle mouse button.
+    ///
+    /// Unlike a regular selection, this leaves the [`Menu`] open, so several
+    /// options can be middle-clicked in a row. Disabled options are ignored.
+    pub fn on_middle_select(
+        mut self,
+        on_middle_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        self.on_middle_select = Some(Box::new(on_middle_select));
+        self
+    }
+
+    /// Keeps the [`Menu`] open when an option is selected while the given
+    /// [`keyboard::Modifiers`] are pressed, so several options can be picked
+    /// in a row.
+    ///
+    /// Every pick publishes the regular selection message; unmodified picks
+    /// select and close as usual. The [`PickList`] always displays the
+    /// `selected` value it was created with, so it is up to the application
+    /// to decide which of the picked options to show.
+    pub fn sticky_open_with(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.sticky_modifiers = Some(modifiers);
+        self
+    }
+
     /// Sets the message that will be produced when the [`PickList`] is opened.
     pub fn on_open(mut self, on_open: Message) -> Self {
         self.on_open = Some(on_open);
@@ -344,10 +502,90 @@ where
     }
 }
 
+impl<'a, Message, Theme, Renderer>
+    PickList<'a, usize, Vec<usize>, usize, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`PickList`] whose options may be of different types,
+    /// e.g. recent custom strings mixed with enum variants.
+    ///
+    /// Options are identified by their index, so both `selected` and the
+    /// message produced by `on_select_with_index` refer to a position in
+    /// `options` instead of a typed value. The labels are taken from the
+    /// [`Display`](fmt::Display) implementation of each option once, when the
+    /// [`PickList`] is created; setting a [`format`](Self::format) replaces
+    /// them and receives indices. Likewise, the `disabled` predicate receives
+    /// the indices of the options.
+    pub fn from_display(
+        options: Vec<Box<dyn fmt::Display + 'a>>,
+        disabled: Option<impl Fn(&[usize]) -> Vec<bool> + 'a>,
+        selected: Option<usize>,
+        on_select_with_index: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        let labels: Vec<String> =
+            options.iter().map(ToString::to_string).collect();
+
+        Self::new(
+            (0..labels.len()).collect(),
+            disabled,
+            selected,
+            on_select_with_index,
+        )
+        .format(move |index| labels[*index].clone())
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer>
+    PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Returns the label of the given option.
+    fn label(&self, option: &T) -> String {
+        self.format
+            .as_ref()
+            .map_or_else(|| option.to_string(), |format| format(option))
+    }
+
+    /// Evaluates the `disabled` predicate, returning exactly one entry per
+    /// option.
+    fn compute_disabled(&self) -> Vec<bool> {
+        let options = self.options.borrow();
+
+        let mut disabled = self
+            .disabled
+            .as_ref()
+            .map(|f| f(options))
+            .unwrap_or_default();
+
+        disabled.resize(options.len(), false);
+        disabled
+    }
+
+    /// Hashes the labels of the options, to detect when they change while
+    /// their number stays the same.
+    fn hash_options(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for option in self.options.borrow() {
+            self.label(option).hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
 impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for PickList<'a, T, L, V, Message, Theme, Renderer>
 where
-    T: Clone + ToString + PartialEq + 'a,
+    T: Clone + ToString + 'a,
     L: Borrow<[T]>,
     V: Borrow<T>,
     Message: Clone + 'a,
@@ -359,7 +597,22 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::<Renderer::Paragraph>::new())
+        tree::State::new(State::<Renderer::Paragraph> {
+            disabled: self.compute_disabled(),
+            ..State::new()
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // Looking at every label on every `view` would be too slow for long
+        // lists, so only the number of options is checked here; their labels
+        // are checked when the menu opens
+        if state.disabled.len() != self.options.borrow().len() {
+            state.disabled = self.compute_disabled();
+            state.menu.reset_scroll();
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -401,7 +654,7 @@ where
 
         for (option, paragraph) in options.iter().zip(state.options.iter_mut())
         {
-            let label = option.to_string();
+            let label = self.label(option);
 
             paragraph.update(Text {
                 content: &label,
@@ -412,6 +665,7 @@ where
         if let Some(placeholder) = &self.placeholder {
             state.placeholder.update(Text {
                 content: placeholder,
+                font: self.placeholder_font.unwrap_or(font),
                 ..option_text
             });
         }
@@ -468,12 +722,9 @@ where
 
                 if state.is_open {
                     if let Some(hovered) = state.hovered_option {
-                        let options = self.options.borrow();
-                        if let Some(disabled_fn) = &self.disabled {
-                            let disabled = disabled_fn(options);
-                            if hovered < disabled.len() && disabled[hovered] {
-                                return event::Status::Captured;
-                            }
+                        if state.disabled.get(hovered).copied().unwrap_or(false)
+                        {
+                            return event::Status::Captured;
                         }
                     }
 
@@ -481,6 +732,13 @@ where
                     // disabled, so cursor was clicked either outside its bounds
                     // or on an enabled option, either way we close the overlay.
                     state.is_open = false;
+                    state.animation = state
+                        .animation
+                        .map(|animation| animation.close(Instant::now()));
+
+                    if state.animation.is_some() {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
 
                     if let Some(on_close) = &self.on_close {
                         shell.publish(on_close.clone());
@@ -490,17 +748,36 @@ where
                 } else if cursor.is_over(layout.bounds()) {
                     let selected = self.selected.as_ref().map(Borrow::borrow);
 
+                    state.disabled = self.compute_disabled();
+
+                    // The scroll position of the menu is kept across
+                    // openings, unless the options changed
+                    let options_hash = self.hash_options();
+
+                    if state.options_hash != Some(options_hash) {
+                        state.options_hash = Some(options_hash);
+                        state.menu.reset_scroll();
+                    }
+
                     state.is_open = true;
-                    state.hovered_option = self
-                        .options
-                        .borrow()
-                        .iter()
-                        .position(|option| Some(option) == selected);
+                    state.animation = self
+                        .is_animated
+                        .then(|| menu::Animation::open(Instant::now()));
+                    state.hovered_option =
+                        self.options.borrow().iter().position(|option| {
+                            selected.is_some_and(|selected| {
+                                (self.eq)(option, selected)
+                            })
+                        });
 
                     if let Some(on_open) = &self.on_open {
                         shell.publish(on_open.clone());
                     }
 
+                    if self.is_animated {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
                     event::Status::Captured
                 } else {
                     event::Status::Ignored
@@ -515,75 +792,42 @@ where
                 if state.keyboard_modifiers.command()
                     && cursor.is_over(layout.bounds())
                     && !state.is_open
+                    && !self.is_loading
                 {
-                    fn find_next<'a, T: PartialEq>(
-                        selected: &'a T,
-                        mut options: impl Iterator<Item = &'a T>,
-                    ) -> Option<&'a T> {
-                        let _ = options.find(|&option| option == selected);
-
-                        options.next()
-                    }
+                    state.disabled = self.compute_disabled();
 
                     let options = self.options.borrow();
-                    let selected = self.selected.as_ref().map(Borrow::borrow);
-                    let disabled = self
-                        .disabled
-                        .as_ref()
-                        .map(|f| f(options))
-                        .unwrap_or_else(|| vec![false; options.len()]);
+                    let disabled = &state.disabled;
+                    let is_enabled = |index: &usize| !disabled[*index];
+
+                    // `None` if nothing is selected, `Some(None)` if the
+                    // selected value is not among the options
+                    let selected = self.selected.as_ref().map(|selected| {
+                        options.iter().position(|option| {
+                            (self.eq)(option, selected.borrow())
+                        })
+                    });
 
                     let next_option = if y < 0.0 {
-                        if let Some(selected) = selected {
-                            let mut next = find_next(selected, options.iter());
-                            // Keep finding next until we hit a non-disabled
-                            // option or run out
-                            while let Some(option) = next {
-                                if let Some(pos) =
-                                    options.iter().position(|opt| opt == option)
-                                {
-                                    if !disabled[pos] {
-                                        break;
-                                    }
-                                }
-                                next = find_next(option, options.iter());
+                        match selected {
+                            Some(Some(index)) => {
+                                (index + 1..options.len()).find(is_enabled)
                             }
-                            next
-                        } else {
-                            options
-                                .iter()
-                                .enumerate()
-                                .find(|(i, _)| !disabled[*i])
-                                .map(|(_, opt)| opt)
+                            Some(None) => None,
+                            None => (0..options.len()).find(is_enabled),
                         }
                     } else if y > 0.0 {
-                        if let Some(selected) = selected {
-                            let mut next =
-                                find_next(selected, options.iter().rev());
-                            // Keep finding next until we hit a non-disabled
-                            // option or run out
-                            while let Some(option) = next {
-                                if let Some(pos) =
-                                    options.iter().position(|opt| opt == option)
-                                {
-                                    if !disabled[pos] {
-                                        break;
-                                    }
-                                }
-                                next = find_next(option, options.iter().rev());
+                        match selected {
+                            Some(Some(index)) => {
+                                (0..index).rev().find(is_enabled)
                             }
-                            next
-                        } else {
-                            options
-                                .iter()
-                                .enumerate()
-                                .rev()
-                                .find(|(i, _)| !disabled[*i])
-                                .map(|(_, opt)| opt)
+                            Some(None) => None,
+                            None => (0..options.len()).rev().find(is_enabled),
                         }
                     } else {
                         None
-                    };
+                    }
+                    .and_then(|index| options.get(index));
 
                     if let Some(next_option) = next_option {
                         shell.publish((self.on_select)(next_option.clone()));
@@ -594,6 +838,19 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                // The menu is gone once it has finished closing
+                if state.animation.is_some_and(|animation| {
+                    animation.is_closing() && animation.is_settled(now)
+                }) {
+                    state.animation = None;
+                }
+
+                event::Status::Ignored
+            }
             Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
                 let state =
                     tree.state.downcast_mut::<State<Renderer::Paragraph>>();
@@ -638,11 +895,7 @@ where
         let selected = self.selected.as_ref().map(Borrow::borrow);
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let options = self.options.borrow();
-        let disabled_options = self
-            .disabled
-            .as_ref()
-            .map(|f| f(options))
-            .unwrap_or_else(|| vec![false; options.len()]);
+        let disabled_options = &state.disabled;
 
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
@@ -731,7 +984,7 @@ where
             );
         }
 
-        let label = selected.map(ToString::to_string);
+        let label = selected.map(|selected| self.label(selected));
 
         if let Some(label) = label.or_else(|| self.placeholder.clone()) {
             let text_size =
@@ -739,11 +992,13 @@ where
 
             // Get the index of the selected item to check if it's disabled
             let selected_index = selected.and_then(|selected| {
-                options.iter().position(|option| option == selected)
+                options
+                    .iter()
+                    .position(|option| (self.eq)(option, selected))
             });
 
             let text_color = if is_selected {
-                if selected_index.map_or(false, |i| disabled_options[i]) {
+                if selected_index.is_some_and(|i| disabled_options[i]) {
                     style.disabled_text_color
                 } else {
                     style.text_color
@@ -757,7 +1012,11 @@ where
                     content: label,
                     size: text_size,
                     line_height: self.text_line_height,
-                    font,
+                    font: if is_selected {
+                        font
+                    } else {
+                        self.placeholder_font.unwrap_or(font)
+                    },
                     bounds: Size::new(
                         bounds.width - self.padding.horizontal(),
                         f32::from(self.text_line_height.to_absolute(text_size)),
@@ -784,19 +1043,61 @@ where
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
         let font = self.font.unwrap_or_else(|| renderer.default_font());
 
-        if state.is_open {
+        if state.is_open
+            || state.animation.is_some_and(menu::Animation::is_closing)
+        {
             let bounds = layout.bounds();
             let options = self.options.borrow();
-            let disabled = self.disabled.as_ref().map(|f| f(options));
+            let animation = state.animation;
+            let disabled =
+                self.disabled.is_some().then(|| state.disabled.clone());
 
             let on_select = &self.on_select;
 
+            // The options are not shown while loading
+            let items = self
+                .view_option
+                .as_ref()
+                .filter(|_| !self.is_loading)
+                .map(|view_option| {
+                    let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                    options
+                        .iter()
+                        .enumerate()
+                        .map(|(index, option)| {
+                            let status = if state.disabled[index] {
+                                OptionStatus::Disabled
+                            } else if state.hovered_option == Some(index) {
+                                OptionStatus::Hovered
+                            } else if selected.is_some_and(|selected| {
+                                (self.eq)(option, selected)
+                            }) {
+                                OptionStatus::Selected
+                            } else {
+                                OptionStatus::Active
+                            };
+
+                            view_option(option, status)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
             let mut menu = Menu::new(
                 &mut state.menu,
                 options,
                 &mut state.hovered_option,
                 |option| {
-                    state.is_open = false;
+                    if !self.sticky_modifiers.is_some_and(|modifiers| {
+                        state.keyboard_modifiers.contains(modifiers)
+                    }) {
+                        state.is_open = false;
+                        state.animation = state
+                            .animation
+                            .map(|animation| animation.close(Instant::now()));
+                    }
+
                     (on_select)(option)
                 },
                 disabled,
@@ -804,14 +1105,31 @@ where
                 &self.menu_class,
             )
             .width(bounds.width)
+            .items(items)
             .padding(self.padding)
             .font(font)
             .text_shaping(self.text_shaping);
 
+            if let Some(format) = &self.format {
+                menu = menu.format(format.as_ref());
+            }
+
+            if let Some(on_middle_select) = &self.on_middle_select {
+                menu = menu.on_middle_selected(on_middle_select.as_ref());
+            }
+
             if let Some(text_size) = self.text_size {
                 menu = menu.text_size(text_size);
             }
 
+            if self.is_loading {
+                menu = menu.loading(&self.loading_text);
+            }
+
+            if let Some(animation) = animation {
+                menu = menu.animate(animation);
+            }
+
             Some(menu.overlay(layout.position() + translation, bounds.height))
         } else {
             None
@@ -823,7 +1141,7 @@ impl<'a, T, L, V, Message, Theme, Renderer>
     From<PickList<'a, T, L, V, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    T: Clone + ToString + PartialEq + 'a,
+    T: Clone + ToString + 'a,
     L: Borrow<[T]> + 'a,
     V: Borrow<T> + 'a,
     Message: Clone + 'a,
@@ -842,7 +1160,10 @@ struct State<P: text::Paragraph> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    animation: Option<menu::Animation>,
     hovered_option: Option<usize>,
+    disabled: Vec<bool>,
+    options_hash: Option<u64>,
     options: Vec<paragraph::Plain<P>>,
     placeholder: paragraph::Plain<P>,
 }
@@ -854,7 +1175,10 @@ impl<P: text::Paragraph> State<P> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            animation: None,
             hovered_option: Option::default(),
+            disabled: Vec::new(),
+            options_hash: None,
             options: Vec::new(),
             placeholder: paragraph::Plain::default(),
         }
@@ -922,14 +1246,30 @@ pub enum Status {
     Opened,
 }
 
+/// The status of an option in the [`Menu`] of a [`PickList`].
+///
+/// See [`PickList::view_option`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionStatus {
+    /// The option can be selected.
+    Active,
+    /// The option is hovered or highlighted with the keyboard.
+    Hovered,
+    /// The option is the selected value of the [`PickList`].
+    Selected,
+    /// The option is disabled.
+    Disabled,
+}
+
 /// The appearance of a pick list.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
-    /// The text [`Color`] of the pick list.
+    /// The text [`Color`] of the selected value of the pick list.
     pub text_color: Color,
     /// The disabled text [`Color`] of the pick list.
     pub disabled_text_color: Color,
-    /// The placeholder [`Color`] of the pick list.
+    /// The text [`Color`] of the placeholder of the pick list, shown while no
+    /// value is selected.
     pub placeholder_color: Color,
     /// The handle [`Color`] of the pick list.
     pub handle_color: Color,
@@ -1013,3 +1353,146 @@ pub const DEFAULT_PADDING: Padding = Padding {
     right: 10.0,
     left: 10.0,
 };
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::*;
+    use crate::test::Simulator;
+    use crate::widget::mouse_area;
+    use iced::time::Duration;
+    use iced::widget::{column, Space};
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Pressed,
+    }
+
+    #[test]
+    fn dismissing_the_menu_does_not_press_ancestors() {
+        let mut simulator = Simulator::<_, Theme>::new(
+            mouse_area(column![
+                PickList::new(
+                    ["a", "b"],
+                    None::<fn(&[&'static str]) -> Vec<bool>>,
+                    None::<&'static str>,
+                    Message::Selected,
+                )
+                .width(Length::Fill),
+                Space::new(Length::Fill, Length::Fill),
+            ])
+            .on_press(Message::Pressed),
+            (200.0, 300.0),
+        );
+
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+        assert_eq!(simulator.click((5.0, 250.0)), vec![]);
+        assert_eq!(simulator.click((5.0, 250.0)), vec![Message::Pressed]);
+
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+        assert_eq!(simulator.tap((5.0, 250.0)), vec![]);
+        assert_eq!(simulator.tap((5.0, 250.0)), vec![Message::Pressed]);
+    }
+
+    #[test]
+    fn options_are_not_selectable_until_revealed() {
+        let options: Vec<&'static str> =
+            vec!["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+        let mut simulator = Simulator::<_, Theme>::new(
+            PickList::new(
+                options,
+                None::<fn(&[&'static str]) -> Vec<bool>>,
+                None::<&'static str>,
+                Message::Selected,
+            )
+            .animated(true)
+            .width(Length::Fill),
+            (200.0, 400.0),
+        );
+
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+
+        let opened_at = Instant::now();
+
+        let _ = simulator.redraw(opened_at);
+        assert_eq!(simulator.click((5.0, 150.0)), vec![]);
+
+        let _ = simulator.redraw(opened_at + Duration::from_secs(1));
+        assert!(matches!(
+            simulator.click((5.0, 150.0)).as_slice(),
+            [Message::Selected(_)]
+        ));
+    }
+
+    #[test]
+    fn closing_menu_lets_presses_through() {
+        let mut simulator = Simulator::<_, Theme>::new(
+            mouse_area(column![
+                PickList::new(
+                    ["a", "b"],
+                    None::<fn(&[&'static str]) -> Vec<bool>>,
+                    None::<&'static str>,
+                    Message::Selected,
+                )
+                .animated(true)
+                .width(Length::Fill),
+                Space::new(Length::Fill, Length::Fill),
+            ])
+            .on_press(Message::Pressed),
+            (200.0, 300.0),
+        );
+
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+
+        let _ = simulator.redraw(Instant::now() + Duration::from_secs(1));
+        assert_eq!(simulator.click((5.0, 45.0)), vec![Message::Selected("a")]);
+
+        let _ = simulator.redraw(Instant::now());
+        assert_eq!(simulator.click((5.0, 45.0)), vec![Message::Pressed]);
+    }
+
+    #[test]
+    fn disabled_options_are_refreshed_before_being_used() {
+        let is_disabled = Rc::new(Cell::new(false));
+
+        let mut simulator = Simulator::<_, Theme>::new(
+            PickList::new(
+                ["a", "b"],
+                Some({
+                    let is_disabled = is_disabled.clone();
+
+                    move |_: &[&'static str]| vec![false, is_disabled.get()]
+                }),
+                Some("a"),
+                Message::Selected,
+            )
+            .width(Length::Fill),
+            (200.0, 300.0),
+        );
+
+        let _ = simulator.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(keyboard::Modifiers::COMMAND),
+        )]);
+        let _ = simulator.move_to((5.0, 5.0));
+
+        let scroll_down = mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 };
+
+        is_disabled.set(true);
+        assert_eq!(simulator.scroll(scroll_down), vec![]);
+
+        is_disabled.set(false);
+        assert_eq!(simulator.scroll(scroll_down), vec![Message::Selected("b")]);
+
+        let _ = simulator.simulate([Event::Keyboard(
+            keyboard::Event::ModifiersChanged(keyboard::Modifiers::empty()),
+        )]);
+
+        is_disabled.set(true);
+        assert_eq!(simulator.click((5.0, 5.0)), vec![]);
+        assert_eq!(simulator.click((5.0, 75.0)), vec![]);
+    }
+}
@@ -0,0 +1,449 @@
+//! Tooltips display a hint of information over some element when hovered,
+//! optionally after a delay.
+//
+// This widget is a modification of the original `Tooltip` widget from [`iced`]
+//
+// [`iced`]: https://github.com/iced-rs/iced
+//
+// Copyright 2019 Héctor Ramón, Iced contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS
+// FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR
+// COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
+// IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
+// CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+use iced::advanced::widget::{tree, Operation, Tree, Widget};
+use iced::advanced::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Shell,
+};
+use iced::event::{self, Event};
+use iced::time::{Duration, Instant};
+use iced::widget::container::{self, Container};
+use iced::window;
+use iced::{Element, Length, Pixels, Point, Rectangle, Size, Vector};
+
+use crate::widget::mouse_area::{self, MouseArea};
+use crate::widget::overlay::{Placement, Positioned};
+
+/// An element to display a widget over another, after an optional delay.
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: renderer::Renderer,
+{
+    content: MouseArea<'a, Message, Theme, Renderer>,
+    tooltip: Container<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    gap: f32,
+    delay: Duration,
+    hide_delay: Duration,
+    follow_cursor: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: container::Catalog,
+    Renderer: renderer::Renderer,
+{
+    /// The default padding of a [`Tooltip`].
+    const DEFAULT_PADDING: f32 = 5.0;
+
+    /// Creates a new [`Tooltip`] displaying `tooltip` when `content` is
+    /// hovered.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Tooltip {
+            content: MouseArea::new(content),
+            tooltip: Container::new(tooltip).padding(Self::DEFAULT_PADDING),
+            placement: Placement::Top,
+            gap: 0.0,
+            delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            follow_cursor: false,
+        }
+    }
+
+    /// Sets the [`Placement`] of the [`Tooltip`] relative to its content.
+    ///
+    /// The [`Tooltip`] is flipped to the opposite side if it does not fit in
+    /// the viewport, and is always kept within it.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the gap between the content and its [`Tooltip`].
+    #[must_use]
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the padding of the [`Tooltip`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.tooltip = self.tooltip.padding(padding.into().0);
+        self
+    }
+
+    /// Sets how long the content must be hovered before the [`Tooltip`] is
+    /// shown.
+    #[must_use]
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets how long the [`Tooltip`] stays visible after the cursor leaves
+    /// the content.
+    ///
+    /// Moving the cursor back over the content during this time keeps the
+    /// [`Tooltip`] open.
+    #[must_use]
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.hide_delay = hide_delay;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`] is placed next to the cursor instead of
+    /// next to the content.
+    #[must_use]
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    #[must_use]
+    pub fn style(
+        mut self,
+        style: impl Fn(&Theme) -> container::Style + 'a,
+    ) -> Self
+    where
+        Theme::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        self.tooltip = self.tooltip.style(style);
+        self
+    }
+
+    /// Sets the style class of the [`Tooltip`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.tooltip = self.tooltip.class(class);
+        self
+    }
+}
+
+/// Local state of the [`Tooltip`].
+///
+/// Hovering itself is tracked by the [`MouseArea`] wrapping the content; this
+/// only keeps track of the delays.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_visible: bool,
+    cursor_position: Option<Point>,
+    hovered_at: Option<Instant>,
+    exited_at: Option<Instant>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tooltip<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: container::Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.content as &dyn Widget<_, _, _>),
+            Tree::new(&self.tooltip as &dyn Widget<_, _, _>),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            &self.content as &dyn Widget<_, _, _>,
+            &self.tooltip as &dyn Widget<_, _, _>,
+        ]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => now,
+            _ => Instant::now(),
+        };
+
+        let status = self.content.on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let area = tree.children[0].state.downcast_ref::<mouse_area::State>();
+        let is_hovered = area.is_hovered();
+        let cursor_position = area.cursor_position();
+
+        let state = tree.state.downcast_mut::<State>();
+        let was_visible = state.is_visible;
+
+        if is_hovered {
+            state.cursor_position = cursor_position;
+            state.exited_at = None;
+
+            let hovered_at = *state.hovered_at.get_or_insert(now);
+
+            if !state.is_visible {
+                let show_at = hovered_at + self.delay;
+
+                if now >= show_at {
+                    state.is_visible = true;
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(show_at));
+                }
+            }
+        } else {
+            state.hovered_at = None;
+
+            if state.is_visible {
+                let exited_at = *state.exited_at.get_or_insert(now);
+                let hide_at = exited_at + self.hide_delay;
+
+                if now >= hide_at {
+                    state.is_visible = false;
+                    state.exited_at = None;
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(hide_at));
+                }
+            }
+        }
+
+        if was_visible != state.is_visible {
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = *tree.state.downcast_ref::<State>();
+
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            translation,
+        );
+
+        let tooltip = state.is_visible.then(|| {
+            let anchor = match state.cursor_position {
+                Some(cursor_position) if self.follow_cursor => {
+                    Rectangle::new(cursor_position, Size::ZERO)
+                }
+                _ => layout.bounds(),
+            };
+
+            // The tooltip is not hit by the cursor, so it never steals the
+            // hover of the content
+            overlay::Element::new(Box::new(Positioned {
+                anchor: (anchor + translation).expand(self.gap),
+                content: &mut self.tooltip,
+                tree: children.next().unwrap(),
+                placement: self.placement,
+                is_interactive: false,
+            }))
+        });
+
+        if content.is_some() || tooltip.is_some() {
+            Some(
+                overlay::Group::with_children(
+                    content.into_iter().chain(tooltip).collect(),
+                )
+                .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: container::Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(tooltip)
+    }
+}
+
+#[cfg(all(test, feature = "test"))]
+mod tests {
+    use super::*;
+    use crate::test::Simulator;
+    use crate::widget::mouse_area;
+    use iced::widget::Space;
+    use iced::Theme;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Shown,
+    }
+
+    /// Presses anywhere and returns whether the bubble reported the press,
+    /// i.e. whether it is shown.
+    fn is_shown(simulator: &mut Simulator<'_, Message, Theme>) -> bool {
+        let messages = simulator.press(mouse::Button::Left);
+        let _ = simulator.release(mouse::Button::Left);
+
+        messages == [Message::Shown]
+    }
+
+    #[test]
+    fn bubble_follows_the_delays() {
+        let bubble =
+            mouse_area(Space::new(20, 20)).on_press_outside(|_| Message::Shown);
+
+        let mut simulator = Simulator::<_, Theme>::new(
+            Tooltip::new(Space::new(100, 40), bubble)
+                .delay(Duration::from_secs(1))
+                .hide_delay(Duration::from_secs(1)),
+            (200.0, 200.0),
+        );
+
+        let hovered_at = Instant::now();
+        let _ = simulator.move_to((50.0, 20.0));
+
+        let _ = simulator.redraw(hovered_at);
+        assert!(!is_shown(&mut simulator));
+
+        let _ = simulator.redraw(hovered_at + Duration::from_secs(2));
+        assert!(is_shown(&mut simulator));
+
+        let exited_at = Instant::now();
+        let _ = simulator.move_to((150.0, 150.0));
+        assert!(is_shown(&mut simulator));
+
+        let _ = simulator.redraw(exited_at + Duration::from_secs(2));
+        assert!(!is_shown(&mut simulator));
+    }
+}